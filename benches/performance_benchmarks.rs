@@ -72,6 +72,21 @@ fn benchmark_ulid_validation(c: &mut Criterion) {
         })
     });
 
+    // Wrong-length input rejected before decoding
+    group.bench_function("validate_wrong_length", |b| {
+        let wrong_length: Vec<&str> = invalid_ulids
+            .iter()
+            .map(|s| s.as_str())
+            .filter(|s| s.len() != 26)
+            .collect();
+        b.iter(|| {
+            for ulid in &wrong_length {
+                let is_valid = UlidEngine::validate(black_box(ulid));
+                black_box(is_valid);
+            }
+        })
+    });
+
     // Batch validation
     for size in [10, 100, 1000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
@@ -0,0 +1,246 @@
+//! ULID conversion to and from other identifier representations.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{UlidEngine, UlidPlugin};
+
+/// Converts ULIDs to and from other representations of their 128 bits.
+///
+/// The only representation so far is `u128`, the decimal form of the 128-bit integer. It is
+/// passed as a string because Nushell integers are 64-bit.
+pub struct UlidConvertCommand;
+
+impl PluginCommand for UlidConvertCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid convert"
+    }
+
+    fn description(&self) -> &str {
+        "Convert a ULID to or from its 128-bit integer form as a decimal string"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .optional(
+                "value",
+                SyntaxShape::String,
+                "The ULID, or decimal string with --from, to convert",
+            )
+            .named(
+                "to",
+                SyntaxShape::String,
+                "Convert a ULID to this representation: 'u128'",
+                None,
+            )
+            .named(
+                "from",
+                SyntaxShape::String,
+                "Convert from this representation to a ULID: 'u128'",
+                None,
+            )
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::String, Type::String),
+            ])
+            .category(Category::Conversions)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid convert '01AN4Z07BY79KA1307SR9X4MV3' --to u128",
+                description: "Get a ULID's 128-bit integer as a decimal string",
+                result: Some(Value::string(
+                    "1772072868548111945193852199469470563",
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                example: "'1772072868548111945193852199469470563' | ulid convert --from u128",
+                description: "Rebuild a ULID from a stored 128-bit integer",
+                result: Some(Value::string(
+                    "01AN4Z07BY79KA1307SR9X4MV3",
+                    Span::test_data(),
+                )),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let to: Option<String> = call.get_flag("to")?;
+        let from: Option<String> = call.get_flag("from")?;
+
+        let value: String = if let Some(arg) = call.opt(0)? {
+            arg
+        } else {
+            match input {
+                PipelineData::Value(Value::String { val, .. }, _) => val,
+                _ => {
+                    return Err(LabeledError::new("Missing value")
+                        .with_label("Provide a value as an argument or via pipeline", call.head));
+                }
+            }
+        };
+
+        let converted = match (to.as_deref(), from.as_deref()) {
+            (Some(to), None) => {
+                check_representation(to, call.head)?;
+                ulid_to_u128(&value, call.head)?
+            }
+            (None, Some(from)) => {
+                check_representation(from, call.head)?;
+                u128_to_ulid(&value, call.head)?
+            }
+            (Some(_), Some(_)) => {
+                return Err(LabeledError::new("Conflicting flags")
+                    .with_label("Use either --to or --from, not both", call.head));
+            }
+            (None, None) => {
+                return Err(LabeledError::new("Missing direction")
+                    .with_label("Use --to or --from to choose a representation", call.head));
+            }
+        };
+
+        Ok(PipelineData::Value(
+            Value::string(converted, call.head),
+            None,
+        ))
+    }
+}
+
+fn check_representation(representation: &str, span: Span) -> Result<(), LabeledError> {
+    if representation == "u128" {
+        Ok(())
+    } else {
+        Err(LabeledError::new("Invalid representation").with_label(
+            format!(
+                "Unknown representation '{}'. Valid representations: u128",
+                representation
+            ),
+            span,
+        ))
+    }
+}
+
+fn ulid_to_u128(ulid_str: &str, span: Span) -> Result<String, LabeledError> {
+    if !UlidEngine::validate(ulid_str) {
+        return Err(LabeledError::new("Invalid ULID")
+            .with_label(format!("'{}' is not a valid ULID", ulid_str), span));
+    }
+
+    let ulid = ulid_str
+        .parse::<ulid::Ulid>()
+        .map_err(|e| LabeledError::new("Parse failed").with_label(e.to_string(), span))?;
+    Ok(u128::from(ulid).to_string())
+}
+
+fn u128_to_ulid(decimal: &str, span: Span) -> Result<String, LabeledError> {
+    if decimal.is_empty() || !decimal.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(LabeledError::new("Invalid u128")
+            .with_label(format!("'{}' is not a decimal integer", decimal), span));
+    }
+
+    let value = decimal.parse::<u128>().map_err(|_| {
+        LabeledError::new("Invalid u128").with_label(
+            format!("'{}' is larger than the 128-bit maximum", decimal),
+            span,
+        )
+    })?;
+    Ok(ulid::Ulid::from(value).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ULID: &str = "01AN4Z07BY79KA1307SR9X4MV3";
+    const ULID_U128: &str = "1772072868548111945193852199469470563";
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    mod convert_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidConvertCommand.signature();
+            assert_eq!(sig.name, "ulid convert");
+            assert!(sig.named.iter().any(|f| f.long == "to"));
+            assert!(sig.named.iter().any(|f| f.long == "from"));
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidConvertCommand.examples().is_empty());
+        }
+    }
+
+    mod u128_tests {
+        use super::*;
+
+        #[test]
+        fn test_known_value() {
+            assert_eq!(ulid_to_u128(ULID, test_span()).unwrap(), ULID_U128);
+            assert_eq!(u128_to_ulid(ULID_U128, test_span()).unwrap(), ULID);
+        }
+
+        #[test]
+        fn test_round_trip() {
+            for _ in 0..10 {
+                let ulid = UlidEngine::generate().unwrap().to_string();
+                let decimal = ulid_to_u128(&ulid, test_span()).unwrap();
+                assert_eq!(u128_to_ulid(&decimal, test_span()).unwrap(), ulid);
+            }
+        }
+
+        #[test]
+        fn test_bounds() {
+            assert_eq!(
+                u128_to_ulid("0", test_span()).unwrap(),
+                "00000000000000000000000000"
+            );
+            assert_eq!(
+                u128_to_ulid(&u128::MAX.to_string(), test_span()).unwrap(),
+                "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+            );
+        }
+
+        #[test]
+        fn test_invalid_ulid_errors() {
+            assert!(ulid_to_u128("invalid", test_span()).is_err());
+        }
+
+        #[test]
+        fn test_invalid_decimal_errors() {
+            for input in ["", "-1", "+1", "12a", "0x10"] {
+                assert!(u128_to_ulid(input, test_span()).is_err(), "{}", input);
+            }
+        }
+
+        #[test]
+        fn test_decimal_above_u128_max_errors() {
+            let error =
+                u128_to_ulid("340282366920938463463374607431768211456", test_span()).unwrap_err();
+            assert!(error.labels[0].text.contains("128-bit maximum"));
+        }
+
+        #[test]
+        fn test_unknown_representation_errors() {
+            assert!(check_representation("u128", test_span()).is_ok());
+            assert!(check_representation("u64", test_span()).is_err());
+        }
+    }
+}
@@ -0,0 +1,343 @@
+//! ULID downsampling command keeping one ULID per time interval.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use crate::{NANOS_PER_MILLI, UlidEngine, UlidPlugin};
+
+/// Keeps one ULID per time interval from a time-ordered list or stream.
+///
+/// Intervals are aligned to the Unix epoch. Like `uniq`, only consecutive ULIDs are grouped,
+/// so input should be sorted first (e.g. with `ulid sort`); an interval that reappears later
+/// starts a new run.
+pub struct UlidDownsampleCommand;
+
+impl PluginCommand for UlidDownsampleCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid downsample"
+    }
+
+    fn description(&self) -> &str {
+        "Keep only the first (or last) ULID in each time interval of a time-ordered list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required_named(
+                "interval",
+                SyntaxShape::Duration,
+                "Width of each time bucket (at least 1ms)",
+                Some('i'),
+            )
+            .switch(
+                "last",
+                "Keep the last ULID in each interval instead of the first",
+                Some('l'),
+            )
+            .named(
+                "column",
+                SyntaxShape::String,
+                "Column containing ULIDs when the input is a list of records",
+                Some('c'),
+            )
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+                (
+                    Type::List(Box::new(Type::Record(vec![].into()))),
+                    Type::List(Box::new(Type::Record(vec![].into()))),
+                ),
+            ])
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "$ids | ulid sort | ulid downsample --interval 1min",
+                description: "Keep the first ULID in each minute",
+                result: None,
+            },
+            Example {
+                example: "open events.csv | ulid sort --column id | ulid downsample --interval 1hr --column id --last",
+                description: "Keep the last event record in each hour",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let interval: Value = call.get_flag("interval")?.ok_or_else(|| {
+            LabeledError::new("Missing interval").with_label("--interval is required", call.head)
+        })?;
+        let keep_last = call.has_flag("last")?;
+        let column: Option<String> = call.get_flag("column")?;
+
+        let interval_ms = interval.as_duration()? / NANOS_PER_MILLI as i64;
+        if interval_ms < 1 {
+            return Err(LabeledError::new("Invalid interval")
+                .with_label("--interval must be at least 1ms", call.head));
+        }
+        let interval_ms = interval_ms as u64;
+
+        match input {
+            PipelineData::Value(Value::List { vals, .. }, _) => {
+                let kept: Vec<Value> =
+                    Downsample::new(vals.into_iter(), interval_ms, keep_last, column, call.head)
+                        .collect();
+                Ok(PipelineData::Value(Value::list(kept, call.head), None))
+            }
+            PipelineData::ListStream(stream, _) => {
+                let span = stream.span();
+                let downsample =
+                    Downsample::new(stream.into_iter(), interval_ms, keep_last, column, span);
+                Ok(PipelineData::ListStream(
+                    ListStream::new(downsample, span, engine.signals().clone()),
+                    None,
+                ))
+            }
+            PipelineData::Empty => Ok(PipelineData::Empty),
+            _ => Err(LabeledError::new("Invalid input").with_label(
+                "Expected a list of ULIDs or records containing ULIDs",
+                call.head,
+            )),
+        }
+    }
+}
+
+/// Streams one value per run of consecutive values in the same time bucket.
+///
+/// Holds at most one value, so memory stays constant regardless of input size. An invalid
+/// item ends the current run: the held value is emitted first and the error after it, so the
+/// output keeps the input order.
+struct Downsample<I> {
+    inner: I,
+    interval_ms: u64,
+    keep_last: bool,
+    column: Option<String>,
+    span: Span,
+    held: Option<(u64, Value)>,
+    pending_error: Option<Value>,
+}
+
+impl<I: Iterator<Item = Value>> Downsample<I> {
+    fn new(
+        inner: I,
+        interval_ms: u64,
+        keep_last: bool,
+        column: Option<String>,
+        span: Span,
+    ) -> Self {
+        Self {
+            inner,
+            interval_ms,
+            keep_last,
+            column,
+            span,
+            held: None,
+            pending_error: None,
+        }
+    }
+
+    fn bucket_of(&self, value: &Value) -> Result<u64, LabeledError> {
+        let ulid_value = match &self.column {
+            Some(column) => match value {
+                Value::Record { val, .. } => val.get(column),
+                _ => None,
+            },
+            None => Some(value),
+        };
+        let ulid_str = ulid_value.and_then(|v| v.as_str().ok()).ok_or_else(|| {
+            let expected = match &self.column {
+                Some(column) => format!("Expected a record with a '{}' ULID column", column),
+                None => "Expected a ULID string".to_string(),
+            };
+            LabeledError::new("Invalid input").with_label(expected, self.span)
+        })?;
+
+        let timestamp = UlidEngine::extract_timestamp(ulid_str)
+            .map_err(|e| LabeledError::new("Invalid ULID").with_label(e.to_string(), self.span))?;
+        Ok(timestamp / self.interval_ms)
+    }
+}
+
+impl<I: Iterator<Item = Value>> Iterator for Downsample<I> {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if let Some(error) = self.pending_error.take() {
+            return Some(error);
+        }
+
+        loop {
+            let Some(value) = self.inner.next() else {
+                return self.held.take().map(|(_, value)| value);
+            };
+
+            let bucket = match self.bucket_of(&value) {
+                Ok(bucket) => bucket,
+                Err(e) => {
+                    let error = Value::error(e.into(), self.span);
+                    return match self.held.take() {
+                        Some((_, held)) => {
+                            self.pending_error = Some(error);
+                            Some(held)
+                        }
+                        None => Some(error),
+                    };
+                }
+            };
+
+            match &mut self.held {
+                Some((current, held)) if *current == bucket => {
+                    if self.keep_last {
+                        *held = value;
+                    }
+                }
+                _ => {
+                    if let Some((_, previous)) = self.held.replace((bucket, value)) {
+                        return Some(previous);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE_MS: u64 = 60_000;
+    const BASE_MS: u64 = 1_700_000_040_000;
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    fn ulid_at(timestamp_ms: u64, random: u128) -> String {
+        ulid::Ulid::from_parts(timestamp_ms, random).to_string()
+    }
+
+    /// Three ULIDs in the first minute, one in the second, two in the third.
+    fn events() -> Vec<String> {
+        vec![
+            ulid_at(BASE_MS, 1),
+            ulid_at(BASE_MS + 10_000, 2),
+            ulid_at(BASE_MS + 59_999, 3),
+            ulid_at(BASE_MS + MINUTE_MS, 4),
+            ulid_at(BASE_MS + 2 * MINUTE_MS + 1, 5),
+            ulid_at(BASE_MS + 2 * MINUTE_MS + 2, 6),
+        ]
+    }
+
+    fn downsample(ids: &[String], keep_last: bool) -> Vec<String> {
+        let vals = ids.iter().map(|id| Value::string(id, test_span()));
+        Downsample::new(vals, MINUTE_MS, keep_last, None, test_span())
+            .map(|v| v.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    mod downsample_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidDownsampleCommand.signature();
+            assert_eq!(sig.name, "ulid downsample");
+            assert!(sig.named.iter().any(|f| f.long == "interval" && f.required));
+            assert!(sig.named.iter().any(|f| f.long == "last"));
+            assert!(sig.named.iter().any(|f| f.long == "column"));
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidDownsampleCommand.examples().is_empty());
+        }
+    }
+
+    mod downsample_tests {
+        use super::*;
+
+        #[test]
+        fn test_keeps_first_per_bucket() {
+            let ids = events();
+            assert_eq!(
+                downsample(&ids, false),
+                vec![ids[0].clone(), ids[3].clone(), ids[4].clone()]
+            );
+        }
+
+        #[test]
+        fn test_keeps_last_per_bucket() {
+            let ids = events();
+            assert_eq!(
+                downsample(&ids, true),
+                vec![ids[2].clone(), ids[3].clone(), ids[5].clone()]
+            );
+        }
+
+        #[test]
+        fn test_empty_input() {
+            assert!(downsample(&[], false).is_empty());
+        }
+
+        #[test]
+        fn test_records_by_column() {
+            let vals = events().into_iter().map(|id| {
+                let mut record = nu_protocol::Record::new();
+                record.push("id", Value::string(id, test_span()));
+                Value::record(record, test_span())
+            });
+            let kept: Vec<Value> =
+                Downsample::new(vals, MINUTE_MS, false, Some("id".to_string()), test_span())
+                    .collect();
+            assert_eq!(kept.len(), 3);
+            assert!(kept.iter().all(|v| v.as_record().is_ok()));
+        }
+
+        #[test]
+        fn test_invalid_ulid_yields_error_value() {
+            let first = ulid_at(BASE_MS, 1);
+            let vals = vec![
+                Value::string(&first, test_span()),
+                Value::string("invalid", test_span()),
+            ];
+            let kept: Vec<Value> =
+                Downsample::new(vals.into_iter(), MINUTE_MS, false, None, test_span()).collect();
+            assert_eq!(kept.len(), 2);
+            assert_eq!(kept[0].as_str().unwrap(), first);
+            assert!(matches!(kept[1], Value::Error { .. }));
+        }
+
+        #[test]
+        fn test_error_keeps_input_order_between_buckets() {
+            let ids = events();
+            let vals = vec![
+                Value::string(&ids[0], test_span()),
+                Value::string(&ids[1], test_span()),
+                Value::int(42, test_span()),
+                Value::string(&ids[3], test_span()),
+            ];
+            let kept: Vec<Value> =
+                Downsample::new(vals.into_iter(), MINUTE_MS, true, None, test_span()).collect();
+            assert_eq!(kept.len(), 3);
+            assert_eq!(kept[0].as_str().unwrap(), ids[1]);
+            assert!(matches!(kept[1], Value::Error { .. }));
+            assert_eq!(kept[2].as_str().unwrap(), ids[3]);
+        }
+    }
+}
@@ -1,12 +1,139 @@
-//! Base32 and hex encoding/decoding commands for ULIDs.
+//! Base32, hex, and Base64 encoding/decoding commands for ULIDs.
+
+use base64::alphabet;
+use base64::engine::DecodePaddingMode;
+use base64::engine::general_purpose::{
+    GeneralPurpose, GeneralPurposeConfig, STANDARD, STANDARD_NO_PAD, URL_SAFE_NO_PAD,
+};
+use std::io::{self, Read};
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
-    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+    ByteStream, ByteStreamType, Category, Example, LabeledError, PipelineData, Signals, Signature,
+    Span, SyntaxShape, Type, Value,
 };
 
 use crate::{UlidEngine, UlidPlugin};
 
+/// Base64 decoder configuration that accepts input with or without padding.
+const BASE64_DECODE_CONFIG: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+
+/// Input bytes per output group: 5 bytes become 8 Base32 characters.
+const BASE32_GROUP_BYTES: usize = 5;
+
+/// Input bytes per output group: 3 bytes become 4 Base64 characters.
+const BASE64_GROUP_BYTES: usize = 3;
+
+/// Number of encoding groups read from a byte stream per encoded chunk.
+const STREAM_CHUNK_GROUPS: usize = 4096;
+
+/// An output encoding with its options, shared by `ulid encode` and its dedicated subcommands.
+#[derive(Clone, Copy)]
+enum Encoding {
+    Base32,
+    Hex { uppercase: bool },
+    Base64 { url_safe: bool, no_pad: bool },
+}
+
+impl Encoding {
+    /// Looks up an `--encoding` name, with every option at its default.
+    fn from_name(name: &str, span: Span) -> Result<Self, LabeledError> {
+        match name {
+            "base32" => Ok(Self::Base32),
+            "hex" => Ok(Self::Hex { uppercase: false }),
+            "base64" => Ok(Self::Base64 {
+                url_safe: false,
+                no_pad: false,
+            }),
+            _ => Err(LabeledError::new("Invalid encoding").with_label(
+                format!(
+                    "Unknown encoding '{}'. Valid encodings: base32, hex, base64",
+                    name
+                ),
+                span,
+            )),
+        }
+    }
+
+    /// Number of input bytes the encoding turns into one fixed-width output group.
+    fn group_size(self) -> usize {
+        match self {
+            Self::Base32 => BASE32_GROUP_BYTES,
+            Self::Hex { .. } => 1,
+            Self::Base64 { .. } => BASE64_GROUP_BYTES,
+        }
+    }
+}
+
+/// Encodes data using a selectable encoding.
+pub struct UlidEncodeCommand;
+
+impl PluginCommand for UlidEncodeCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid encode"
+    }
+
+    fn description(&self) -> &str {
+        "Encode data to base32 (Crockford), hex, or base64"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .optional(
+                "data",
+                SyntaxShape::Any,
+                "Data to encode (string or binary)",
+            )
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "Encoding: 'base32', 'hex', 'base64' (default: base32)",
+                Some('e'),
+            )
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Binary, Type::String),
+            ])
+            .category(Category::Hash)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid encode 'hello'",
+                description: "Encode a string to Crockford Base32",
+                result: Some(Value::string("CSQPYRK1E8", Span::test_data())),
+            },
+            Example {
+                example: "ulid encode 'hello' --encoding hex",
+                description: "Encode a string to hex",
+                result: Some(Value::string("68656c6c6f", Span::test_data())),
+            },
+            Example {
+                example: "ulid to-bytes '01AN4Z07BY79KA1307SR9X4MV3' | ulid encode --encoding base64",
+                description: "Encode a binary ULID to Base64",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let encoding: Option<String> = call.get_flag("encoding")?;
+        let encoding = Encoding::from_name(encoding.as_deref().unwrap_or("base32"), call.head)?;
+
+        encode_input(call, input, encoding, engine.signals().clone())
+    }
+}
+
 /// Encodes data using Crockford Base32.
 pub struct UlidEncodeBase32Command;
 
@@ -47,40 +174,97 @@ impl PluginCommand for UlidEncodeBase32Command {
                 description: "Encode binary data to Base32",
                 result: None,
             },
+            Example {
+                example: "open --raw backup.bin | ulid encode base32 | save backup.b32",
+                description: "Encode a large file as it streams, without loading it into memory",
+                result: None,
+            },
         ]
     }
 
     fn run(
         &self,
         _plugin: &Self::Plugin,
-        _engine: &EngineInterface,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
-        let data = if let Some(arg) = call.opt::<Value>(0)? {
-            // Using positional argument
-            match arg {
-                Value::String { val, .. } => val.into_bytes(),
-                Value::Binary { val, .. } => val,
-                _ => {
-                    return Err(LabeledError::new("Invalid input type")
-                        .with_label("Expected string or binary data", call.head));
-                }
-            }
-        } else {
-            // Using pipeline input
-            match input {
-                PipelineData::Value(Value::String { val, .. }, _) => val.into_bytes(),
-                PipelineData::Value(Value::Binary { val, .. }, _) => val,
-                _ => {
-                    return Err(LabeledError::new("Invalid input type")
-                        .with_label("Expected string or binary data from pipeline", call.head));
-                }
-            }
+        encode_input(call, input, Encoding::Base32, engine.signals().clone())
+    }
+}
+
+/// Decodes data from a selectable or auto-detected encoding.
+pub struct UlidDecodeCommand;
+
+impl PluginCommand for UlidDecodeCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid decode"
+    }
+
+    fn description(&self) -> &str {
+        "Decode base32 (Crockford), hex, or base64 data, detecting the encoding by default"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("data", SyntaxShape::String, "Encoded string to decode")
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "Encoding: 'auto', 'base32', 'hex', 'base64' (default: auto)",
+                Some('e'),
+            )
+            .switch("text", "Output as text instead of binary", Some('t'))
+            .input_output_types(vec![
+                (Type::Nothing, Type::Binary),
+                (Type::Nothing, Type::String),
+            ])
+            .category(Category::Hash)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid decode 'CSQPYRK1E8' --text",
+                description: "Decode Crockford Base32, detected automatically",
+                result: Some(Value::string("hello", Span::test_data())),
+            },
+            Example {
+                example: "ulid decode 'aGVsbG8=' --text",
+                description: "Decode Base64, detected automatically",
+                result: Some(Value::string("hello", Span::test_data())),
+            },
+            Example {
+                example: "ulid decode 'ABCDEFGH' --encoding base64",
+                description: "Name the encoding when auto-detection is ambiguous",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let data: String = call.req(0)?;
+        let encoding: Option<String> = call.get_flag("encoding")?;
+        let as_text = call.has_flag("text")?;
+
+        let encoding = match encoding.as_deref() {
+            Some("auto") | None => detect_encoding(&data, call.head)?,
+            Some(encoding) => encoding,
         };
 
-        let encoded = base32::encode(base32::Alphabet::Crockford, &data);
-        Ok(PipelineData::Value(Value::string(encoded, call.head), None))
+        let decoded = decode_str(&data, encoding, call.head)?;
+        Ok(PipelineData::Value(
+            decoded_to_value(decoded, as_text, call.head)?,
+            None,
+        ))
     }
 }
 
@@ -102,6 +286,16 @@ impl PluginCommand for UlidDecodeBase32Command {
         Signature::build(self.name())
             .required("data", SyntaxShape::String, "Base32 string to decode")
             .switch("text", "Output as text instead of binary", Some('t'))
+            .switch(
+                "strict",
+                "Reject lowercase and the ambiguous I, L, and O instead of normalizing them",
+                Some('s'),
+            )
+            .switch(
+                "as-ulid",
+                "Read exactly 26 characters as a ULID and return its canonical string",
+                Some('u'),
+            )
             .input_output_types(vec![
                 (Type::String, Type::Binary),
                 (Type::String, Type::String),
@@ -121,6 +315,19 @@ impl PluginCommand for UlidDecodeBase32Command {
                 description: "Decode Base32 to text",
                 result: Some(Value::string("hello", Span::test_data())),
             },
+            Example {
+                example: "ulid decode base32 'CSQPYRKIE8' --strict",
+                description: "Fail on the ambiguous 'I' instead of reading it as '1'",
+                result: None,
+            },
+            Example {
+                example: "ulid decode base32 '01an4z07by79ka13o7sr9x4mv3' --as-ulid",
+                description: "Normalize a hand-typed ULID to its canonical string",
+                result: Some(Value::string(
+                    "01AN4Z07BY79KA1307SR9X4MV3",
+                    Span::test_data(),
+                )),
+            },
         ]
     }
 
@@ -133,26 +340,26 @@ impl PluginCommand for UlidDecodeBase32Command {
     ) -> Result<PipelineData, LabeledError> {
         let data: String = call.req(0)?;
         let as_text = call.has_flag("text")?;
+        let strict = call.has_flag("strict")?;
+
+        if strict {
+            check_canonical_base32(&data, call.head)?;
+        }
 
-        match base32::decode(base32::Alphabet::Crockford, &data) {
-            Some(decoded) => {
-                let result = if as_text {
-                    match String::from_utf8(decoded) {
-                        Ok(text) => Value::string(text, call.head),
-                        Err(_) => {
-                            return Err(LabeledError::new("Invalid UTF-8")
-                                .with_label("Decoded data is not valid UTF-8 text", call.head));
-                        }
-                    }
-                } else {
-                    Value::binary(decoded, call.head)
-                };
-
-                Ok(PipelineData::Value(result, None))
+        if call.has_flag("as-ulid")? {
+            if as_text {
+                return Err(LabeledError::new("Conflicting flags")
+                    .with_label("Use either --text or --as-ulid, not both", call.head));
             }
-            None => Err(LabeledError::new("Invalid Base32")
-                .with_label("Failed to decode Base32 data", call.head)),
+            let ulid = base32_to_ulid(&data, call.head)?;
+            return Ok(PipelineData::Value(Value::string(ulid, call.head), None));
         }
+
+        let decoded = decode_str(&data, "base32", call.head)?;
+        Ok(PipelineData::Value(
+            decoded_to_value(decoded, as_text, call.head)?,
+            None,
+        ))
     }
 }
 
@@ -203,41 +410,18 @@ impl PluginCommand for UlidEncodeHexCommand {
     fn run(
         &self,
         _plugin: &Self::Plugin,
-        _engine: &EngineInterface,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let uppercase = call.has_flag("uppercase")?;
 
-        let data = if let Some(arg) = call.opt::<Value>(0)? {
-            // Using positional argument
-            match arg {
-                Value::String { val, .. } => val.into_bytes(),
-                Value::Binary { val, .. } => val,
-                _ => {
-                    return Err(LabeledError::new("Invalid input type")
-                        .with_label("Expected string or binary data", call.head));
-                }
-            }
-        } else {
-            // Using pipeline input
-            match input {
-                PipelineData::Value(Value::String { val, .. }, _) => val.into_bytes(),
-                PipelineData::Value(Value::Binary { val, .. }, _) => val,
-                _ => {
-                    return Err(LabeledError::new("Invalid input type")
-                        .with_label("Expected string or binary data from pipeline", call.head));
-                }
-            }
-        };
-
-        let encoded = if uppercase {
-            hex::encode_upper(&data)
-        } else {
-            hex::encode(&data)
-        };
-
-        Ok(PipelineData::Value(Value::string(encoded, call.head), None))
+        encode_input(
+            call,
+            input,
+            Encoding::Hex { uppercase },
+            engine.signals().clone(),
+        )
     }
 }
 
@@ -259,6 +443,11 @@ impl PluginCommand for UlidDecodeHexCommand {
         Signature::build(self.name())
             .required("data", SyntaxShape::String, "Hex string to decode")
             .switch("text", "Output as text instead of binary", Some('t'))
+            .switch(
+                "as-ulid",
+                "Decode exactly 32 hex characters to a canonical ULID string",
+                Some('u'),
+            )
             .input_output_types(vec![
                 (Type::String, Type::Binary),
                 (Type::String, Type::String),
@@ -278,6 +467,19 @@ impl PluginCommand for UlidDecodeHexCommand {
                 description: "Decode hex to text",
                 result: Some(Value::string("hello", Span::test_data())),
             },
+            Example {
+                example: "ulid decode hex '015549f01d7e3a66a08c07ce13d25363' --as-ulid",
+                description: "Reconstruct a ULID from its hex representation",
+                result: Some(Value::string(
+                    "01AN4Z07BY79KA1307SR9X4MV3",
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                example: "ulid decode hex ($stored | bytes reverse | encode hex) --as-ulid",
+                description: "Reconstruct a ULID whose bytes were stored little-endian",
+                result: None,
+            },
         ]
     }
 
@@ -291,29 +493,160 @@ impl PluginCommand for UlidDecodeHexCommand {
         let data: String = call.req(0)?;
         let as_text = call.has_flag("text")?;
 
-        match hex::decode(&data) {
-            Ok(decoded) => {
-                let result = if as_text {
-                    match String::from_utf8(decoded) {
-                        Ok(text) => Value::string(text, call.head),
-                        Err(_) => {
-                            return Err(LabeledError::new("Invalid UTF-8")
-                                .with_label("Decoded data is not valid UTF-8 text", call.head));
-                        }
-                    }
-                } else {
-                    Value::binary(decoded, call.head)
-                };
-
-                Ok(PipelineData::Value(result, None))
+        if call.has_flag("as-ulid")? {
+            if as_text {
+                return Err(LabeledError::new("Conflicting flags")
+                    .with_label("Use either --text or --as-ulid, not both", call.head));
             }
-            Err(e) => Err(LabeledError::new("Invalid hex")
-                .with_label(format!("Failed to decode hex data: {}", e), call.head)),
+            let ulid = hex_to_ulid(&data, call.head)?;
+            return Ok(PipelineData::Value(Value::string(ulid, call.head), None));
         }
+
+        let decoded = decode_str(&data, "hex", call.head)?;
+        Ok(PipelineData::Value(
+            decoded_to_value(decoded, as_text, call.head)?,
+            None,
+        ))
+    }
+}
+
+/// Encodes data as Base64.
+pub struct UlidEncodeBase64Command;
+
+impl PluginCommand for UlidEncodeBase64Command {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid encode base64"
+    }
+
+    fn description(&self) -> &str {
+        "Encode data to Base64, optionally with the URL-safe alphabet"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .optional(
+                "data",
+                SyntaxShape::Any,
+                "Data to encode (string or binary)",
+            )
+            .switch(
+                "url-safe",
+                "Use the URL-safe alphabet ('-' and '_') without padding",
+                Some('u'),
+            )
+            .switch("no-pad", "Omit trailing '=' padding", Some('n'))
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::Binary, Type::String),
+            ])
+            .category(Category::Hash)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid encode base64 'hello'",
+                description: "Encode a string to Base64",
+                result: Some(Value::string("aGVsbG8=", Span::test_data())),
+            },
+            Example {
+                example: "0xfbff | ulid encode base64 --url-safe",
+                description: "Encode binary data to URL-safe Base64",
+                result: Some(Value::string("-_8", Span::test_data())),
+            },
+            Example {
+                example: "ulid encode base64 'hello' --no-pad",
+                description: "Encode a string to Base64 without padding",
+                result: Some(Value::string("aGVsbG8", Span::test_data())),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let url_safe = call.has_flag("url-safe")?;
+        let no_pad = call.has_flag("no-pad")?;
+
+        encode_input(
+            call,
+            input,
+            Encoding::Base64 { url_safe, no_pad },
+            engine.signals().clone(),
+        )
+    }
+}
+
+/// Decodes Base64 data.
+pub struct UlidDecodeBase64Command;
+
+impl PluginCommand for UlidDecodeBase64Command {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid decode base64"
+    }
+
+    fn description(&self) -> &str {
+        "Decode Base64 data, padded or unpadded"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("data", SyntaxShape::String, "Base64 string to decode")
+            .switch("url-safe", "Decode the URL-safe alphabet", Some('u'))
+            .switch("text", "Output as text instead of binary", Some('t'))
+            .input_output_types(vec![
+                (Type::String, Type::Binary),
+                (Type::String, Type::String),
+            ])
+            .category(Category::Hash)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid decode base64 'aGVsbG8=' --text",
+                description: "Decode Base64 to text",
+                result: Some(Value::string("hello", Span::test_data())),
+            },
+            Example {
+                example: "ulid decode base64 '-_8' --url-safe",
+                description: "Decode URL-safe Base64 to binary",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let data: String = call.req(0)?;
+        let url_safe = call.has_flag("url-safe")?;
+        let as_text = call.has_flag("text")?;
+
+        let decoded = decode_base64(&data, url_safe, call.head)?;
+        Ok(PipelineData::Value(
+            decoded_to_value(decoded, as_text, call.head)?,
+            None,
+        ))
     }
 }
 
 /// Converts a ULID string to its native 16-byte binary representation.
+///
+/// The bytes are big-endian, as in the ULID spec: the timestamp comes first, so byte order
+/// matches sort order.
 pub struct UlidToBytesCommand;
 
 impl PluginCommand for UlidToBytesCommand {
@@ -324,7 +657,7 @@ impl PluginCommand for UlidToBytesCommand {
     }
 
     fn description(&self) -> &str {
-        "Convert a ULID to its native 16-byte binary representation"
+        "Convert a ULID to its native 16-byte, big-endian binary representation"
     }
 
     fn signature(&self) -> Signature {
@@ -387,10 +720,600 @@ impl PluginCommand for UlidToBytesCommand {
     }
 }
 
+fn read_data_input(call: &EvaluatedCall, input: PipelineData) -> Result<Vec<u8>, LabeledError> {
+    if let Some(arg) = call.opt::<Value>(0)? {
+        // Using positional argument
+        match arg {
+            Value::String { val, .. } => Ok(val.into_bytes()),
+            Value::Binary { val, .. } => Ok(val),
+            _ => Err(LabeledError::new("Invalid input type")
+                .with_label("Expected string or binary data", call.head)),
+        }
+    } else {
+        // Using pipeline input
+        match input {
+            PipelineData::Value(Value::String { val, .. }, _) => Ok(val.into_bytes()),
+            PipelineData::Value(Value::Binary { val, .. }, _) => Ok(val),
+            _ => Err(LabeledError::new("Invalid input type")
+                .with_label("Expected string or binary data from pipeline", call.head)),
+        }
+    }
+}
+
+/// Encodes the command's data, streaming piped byte streams through `encoding` chunk by chunk
+/// instead of collecting them into memory first.
+fn encode_input(
+    call: &EvaluatedCall,
+    input: PipelineData,
+    encoding: Encoding,
+    signals: Signals,
+) -> Result<PipelineData, LabeledError> {
+    match input {
+        PipelineData::ByteStream(stream, _) if call.positional.is_empty() => {
+            let span = stream.span();
+            let output = match stream.reader() {
+                Some(reader) => PipelineData::ByteStream(
+                    ByteStream::read(
+                        EncodingReader::new(reader, encoding),
+                        span,
+                        signals,
+                        ByteStreamType::String,
+                    ),
+                    None,
+                ),
+                None => PipelineData::Value(Value::string(String::new(), span), None),
+            };
+            Ok(output)
+        }
+        input => {
+            let data = read_data_input(call, input)?;
+            Ok(PipelineData::Value(
+                Value::string(encode_bytes(&data, encoding), call.head),
+                None,
+            ))
+        }
+    }
+}
+
+/// Encodes a reader incrementally, one chunk of whole encoding groups at a time.
+///
+/// Every chunk but the last is a multiple of the group size, so the concatenated output
+/// matches encoding the whole input at once, including Base64 padding only at the end.
+struct EncodingReader<R> {
+    source: R,
+    chunk: Vec<u8>,
+    encoding: Encoding,
+    encoded: Vec<u8>,
+    position: usize,
+}
+
+impl<R: Read> EncodingReader<R> {
+    fn new(source: R, encoding: Encoding) -> Self {
+        Self {
+            source,
+            chunk: vec![0; encoding.group_size() * STREAM_CHUNK_GROUPS],
+            encoding,
+            encoded: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Fills the chunk buffer, stopping short only at the end of input.
+    fn fill_chunk(&mut self) -> io::Result<usize> {
+        let mut filled = 0;
+        while filled < self.chunk.len() {
+            match self.source.read(&mut self.chunk[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(filled)
+    }
+}
+
+impl<R: Read> Read for EncodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.encoded.len() {
+            let filled = self.fill_chunk()?;
+            if filled == 0 {
+                return Ok(0);
+            }
+            self.encoded = encode_bytes(&self.chunk[..filled], self.encoding).into_bytes();
+            self.position = 0;
+        }
+
+        let count = buf.len().min(self.encoded.len() - self.position);
+        buf[..count].copy_from_slice(&self.encoded[self.position..self.position + count]);
+        self.position += count;
+        Ok(count)
+    }
+}
+
+fn encode_bytes(data: &[u8], encoding: Encoding) -> String {
+    match encoding {
+        Encoding::Base32 => base32::encode(base32::Alphabet::Crockford, data),
+        Encoding::Hex { uppercase: false } => hex::encode(data),
+        Encoding::Hex { uppercase: true } => hex::encode_upper(data),
+        Encoding::Base64 { url_safe, no_pad } => encode_base64(data, url_safe, no_pad),
+    }
+}
+
+fn decode_str(data: &str, encoding: &str, span: Span) -> Result<Vec<u8>, LabeledError> {
+    match encoding {
+        "base32" => base32::decode(base32::Alphabet::Crockford, data).ok_or_else(|| {
+            LabeledError::new("Invalid Base32").with_label("Failed to decode Base32 data", span)
+        }),
+        "hex" => hex::decode(data).map_err(|e| {
+            LabeledError::new("Invalid hex")
+                .with_label(format!("Failed to decode hex data: {}", e), span)
+        }),
+        "base64" => decode_base64(data, false, span),
+        _ => Err(LabeledError::new("Invalid encoding").with_label(
+            format!(
+                "Unknown encoding '{}'. Valid encodings: auto, base32, hex, base64",
+                encoding
+            ),
+            span,
+        )),
+    }
+}
+
+/// Fails on the first character outside the canonical Crockford Base32 alphabet.
+///
+/// The Crockford decoder accepts lowercase and reads `I` and `L` as `1` and `O` as `0`, which
+/// can silently correct a typo.
+fn check_canonical_base32(data: &str, span: Span) -> Result<(), LabeledError> {
+    match data
+        .char_indices()
+        .find(|(_, c)| !crate::CROCKFORD_BASE32_CHARSET.contains(*c))
+    {
+        Some((position, c)) => Err(LabeledError::new("Invalid Base32").with_label(
+            format!(
+                "'{}' at position {} is not in the canonical Crockford Base32 alphabet",
+                c, position
+            ),
+            span,
+        )),
+        None => Ok(()),
+    }
+}
+
+fn encode_base64(data: &[u8], url_safe: bool, no_pad: bool) -> String {
+    let engine = if url_safe {
+        &URL_SAFE_NO_PAD
+    } else if no_pad {
+        &STANDARD_NO_PAD
+    } else {
+        &STANDARD
+    };
+    base64::Engine::encode(engine, data)
+}
+
+fn decode_base64(data: &str, url_safe: bool, span: Span) -> Result<Vec<u8>, LabeledError> {
+    let alphabet = if url_safe {
+        &alphabet::URL_SAFE
+    } else {
+        &alphabet::STANDARD
+    };
+    let engine = GeneralPurpose::new(alphabet, BASE64_DECODE_CONFIG);
+    base64::Engine::decode(&engine, data).map_err(|e| {
+        LabeledError::new("Invalid Base64")
+            .with_label(format!("Failed to decode Base64 data: {}", e), span)
+    })
+}
+
+fn hex_to_ulid(data: &str, span: Span) -> Result<String, LabeledError> {
+    if data.len() != crate::ULID_BYTE_LENGTH * 2 {
+        return Err(LabeledError::new("Invalid ULID hex").with_label(
+            format!(
+                "Expected {} hex characters, got {}",
+                crate::ULID_BYTE_LENGTH * 2,
+                data.len()
+            ),
+            span,
+        ));
+    }
+
+    let bytes: [u8; crate::ULID_BYTE_LENGTH] =
+        decode_str(data, "hex", span)?.try_into().map_err(|_| {
+            LabeledError::new("Invalid ULID hex")
+                .with_label("Hex does not decode to 16 bytes", span)
+        })?;
+    Ok(ulid::Ulid::from_bytes(bytes).to_string())
+}
+
+/// Reads a 26-character Crockford Base32 string as a ULID and returns its canonical form.
+///
+/// Plain Base32 decoding of a ULID string gives the wrong bytes, because the ULID's 128 bits
+/// are right-aligned in its 130-bit text form. Lowercase and the `I`, `L`, and `O` aliases
+/// are normalized as in lenient decoding.
+fn base32_to_ulid(data: &str, span: Span) -> Result<String, LabeledError> {
+    if data.len() != crate::ULID_STRING_LENGTH {
+        return Err(LabeledError::new("Invalid ULID Base32").with_label(
+            format!(
+                "Expected {} Base32 characters, got {}",
+                crate::ULID_STRING_LENGTH,
+                data.len()
+            ),
+            span,
+        ));
+    }
+
+    let normalized: String = data
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            other => other,
+        })
+        .collect();
+
+    // The first character carries only the top 3 bits, so anything above '7' overflows
+    if normalized.as_bytes()[0] > b'7' {
+        return Err(LabeledError::new("Invalid ULID Base32")
+            .with_label(format!("'{}' overflows the 128-bit ULID range", data), span));
+    }
+
+    ulid::Ulid::from_string(&normalized)
+        .map(|ulid| ulid.to_string())
+        .map_err(|e| {
+            LabeledError::new("Invalid ULID Base32")
+                .with_label(format!("Failed to decode '{}': {}", data, e), span)
+        })
+}
+
+fn decoded_to_value(decoded: Vec<u8>, as_text: bool, span: Span) -> Result<Value, LabeledError> {
+    if as_text {
+        String::from_utf8(decoded)
+            .map(|text| Value::string(text, span))
+            .map_err(|_| {
+                LabeledError::new("Invalid UTF-8")
+                    .with_label("Decoded data is not valid UTF-8 text", span)
+            })
+    } else {
+        Ok(Value::binary(decoded, span))
+    }
+}
+
+/// Guesses the encoding of `data` from its alphabet and length.
+///
+/// Exactly one of hex, base32, and base64 must match. Base32 is recognised by the uppercase
+/// output of the Crockford encoder. Hex digits are also valid in the other two alphabets, so
+/// hex-looking input of a length they accept, such as `0123456789`, is ambiguous.
+fn detect_encoding(data: &str, span: Span) -> Result<&'static str, LabeledError> {
+    let is_hex =
+        !data.is_empty() && data.len() % 2 == 0 && data.chars().all(|c| c.is_ascii_hexdigit());
+
+    let is_base32 = !data.is_empty()
+        && matches!(data.len() % 8, 0 | 2 | 4 | 5 | 7)
+        && data
+            .chars()
+            .all(|c| crate::CROCKFORD_BASE32_CHARSET.contains(c));
+
+    let unpadded = data.trim_end_matches('=');
+    let is_base64 = !data.is_empty()
+        && data.len() % 4 == 0
+        && data.len() - unpadded.len() <= 2
+        && unpadded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/');
+
+    let candidates: Vec<&'static str> = [
+        ("hex", is_hex),
+        ("base32", is_base32),
+        ("base64", is_base64),
+    ]
+    .into_iter()
+    .filter_map(|(name, matched)| matched.then_some(name))
+    .collect();
+
+    match candidates.as_slice() {
+        [encoding] => Ok(*encoding),
+        [] => Err(LabeledError::new("Unknown encoding")
+            .with_label("Input does not look like base32, hex, or base64", span)),
+        _ => Err(LabeledError::new("Ambiguous encoding").with_label(
+            format!(
+                "Input could be {}; pass --encoding to choose",
+                candidates.join(" or ")
+            ),
+            span,
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    mod ulid_decode_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidDecodeCommand.signature();
+            assert_eq!(sig.name, "ulid decode");
+            assert_eq!(sig.required_positional.len(), 1);
+            assert!(sig.named.iter().any(|f| f.long == "encoding"));
+            assert!(sig.named.iter().any(|f| f.long == "text"));
+        }
+
+        #[test]
+        fn test_detects_unambiguous_inputs() {
+            let span = Span::test_data();
+            assert_eq!(detect_encoding("68656c6c6f", span).unwrap(), "hex");
+            assert_eq!(detect_encoding("CSQPYRK1E8", span).unwrap(), "base32");
+            assert_eq!(detect_encoding("aGVsbG8=", span).unwrap(), "base64");
+        }
+
+        #[test]
+        fn test_auto_detected_inputs_decode_to_same_bytes() {
+            let span = Span::test_data();
+            for input in ["68656c6c6f", "CSQPYRK1E8", "aGVsbG8="] {
+                let encoding = detect_encoding(input, span).unwrap();
+                assert_eq!(decode_str(input, encoding, span).unwrap(), b"hello");
+            }
+        }
+
+        #[test]
+        fn test_ambiguous_input_errors() {
+            // Valid uppercase Crockford Base32 and valid unpadded Base64
+            assert!(detect_encoding("ABCDEFGH", Span::test_data()).is_err());
+        }
+
+        #[test]
+        fn test_hex_that_is_also_base32_is_ambiguous() {
+            let error = detect_encoding("0123456789", Span::test_data()).unwrap_err();
+            assert_eq!(error.msg, "Ambiguous encoding");
+            assert!(error.labels[0].text.contains("hex or base32"));
+            assert!(error.labels[0].text.contains("--encoding"));
+        }
+
+        #[test]
+        fn test_base64_decodes_with_or_without_padding() {
+            let span = Span::test_data();
+            assert_eq!(decode_str("aGVsbG8=", "base64", span).unwrap(), b"hello");
+            assert_eq!(decode_str("aGVsbG8", "base64", span).unwrap(), b"hello");
+            assert_eq!(
+                decode_str("aGVsbG8", "base64", span).unwrap(),
+                decode_base64("aGVsbG8", false, span).unwrap()
+            );
+        }
+
+        #[test]
+        fn test_unrecognised_input_errors() {
+            assert!(detect_encoding("not encoded!", Span::test_data()).is_err());
+            // Former Base58 output of "hello" is no longer recognised
+            assert!(detect_encoding("Cn8eVZg", Span::test_data()).is_err());
+            assert!(detect_encoding("", Span::test_data()).is_err());
+        }
+
+        #[test]
+        fn test_text_output_requires_utf8() {
+            let span = Span::test_data();
+            assert!(decoded_to_value(vec![0xff, 0xfe], true, span).is_err());
+            assert!(decoded_to_value(vec![0xff, 0xfe], false, span).is_ok());
+        }
+    }
+
+    mod ulid_encode_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidEncodeCommand.signature();
+            assert_eq!(sig.name, "ulid encode");
+            assert_eq!(sig.optional_positional.len(), 1);
+            assert!(sig.named.iter().any(|f| f.long == "encoding"));
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidEncodeCommand.examples().is_empty());
+        }
+
+        #[test]
+        fn test_each_encoding() {
+            let span = Span::test_data();
+            let encode = |name| encode_bytes(b"hello", Encoding::from_name(name, span).unwrap());
+            assert_eq!(encode("base32"), "CSQPYRK1E8");
+            assert_eq!(encode("hex"), "68656c6c6f");
+            assert_eq!(encode("base64"), "aGVsbG8=");
+        }
+
+        #[test]
+        fn test_matches_dedicated_commands() {
+            let span = Span::test_data();
+            let call = EvaluatedCall::new(span).with_positional(Value::string("hello", span));
+            let run = |encoding| {
+                encode_input(&call, PipelineData::Empty, encoding, Signals::empty())
+                    .unwrap()
+                    .into_value(span)
+                    .unwrap()
+            };
+
+            // Each dedicated subcommand without flags passes these encodings to encode_input
+            let dedicated = [
+                ("base32", Encoding::Base32),
+                ("hex", Encoding::Hex { uppercase: false }),
+                (
+                    "base64",
+                    Encoding::Base64 {
+                        url_safe: false,
+                        no_pad: false,
+                    },
+                ),
+            ];
+            for (name, encoding) in dedicated {
+                let unified = Encoding::from_name(name, span).unwrap();
+                assert_eq!(run(unified), run(encoding), "{}", name);
+            }
+        }
+
+        #[test]
+        fn test_streams_byte_stream_input() {
+            let span = Span::test_data();
+            let data = b"hello".to_vec();
+            let stream = ByteStream::read_binary(data.clone(), span, Signals::empty());
+
+            let output = encode_input(
+                &EvaluatedCall::new(span),
+                PipelineData::ByteStream(stream, None),
+                Encoding::from_name("base32", span).unwrap(),
+                Signals::empty(),
+            )
+            .unwrap();
+
+            assert!(matches!(output, PipelineData::ByteStream(..)));
+            assert_eq!(
+                output.into_value(span).unwrap().as_str().unwrap(),
+                encode_bytes(&data, Encoding::Base32)
+            );
+        }
+
+        #[test]
+        fn test_unknown_encoding_errors() {
+            assert!(Encoding::from_name("base85", Span::test_data()).is_err());
+            assert!(Encoding::from_name("base58", Span::test_data()).is_err());
+        }
+    }
+
+    mod ulid_decode_hex_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidDecodeHexCommand.signature();
+            assert_eq!(sig.name, "ulid decode hex");
+            assert!(sig.named.iter().any(|f| f.long == "as-ulid"));
+        }
+
+        #[test]
+        fn test_hex_to_ulid_roundtrip() {
+            let ulid = UlidEngine::generate().unwrap();
+            let hex = hex::encode(UlidEngine::to_bytes(&ulid));
+            assert_eq!(
+                hex_to_ulid(&hex, Span::test_data()).unwrap(),
+                ulid.to_string()
+            );
+            assert_eq!(
+                hex_to_ulid(&hex.to_uppercase(), Span::test_data()).unwrap(),
+                ulid.to_string()
+            );
+        }
+
+        #[test]
+        fn test_hex_to_ulid_known_value() {
+            assert_eq!(
+                hex_to_ulid("015549f01d7e3a66a08c07ce13d25363", Span::test_data()).unwrap(),
+                "01AN4Z07BY79KA1307SR9X4MV3"
+            );
+        }
+
+        #[test]
+        fn test_hex_to_ulid_wrong_length_errors() {
+            let span = Span::test_data();
+            assert!(hex_to_ulid("015549f01d7e3a66a08c07ce13d253", span).is_err());
+            assert!(hex_to_ulid("015549f01d7e3a66a08c07ce13d2536300", span).is_err());
+            assert!(hex_to_ulid("", span).is_err());
+        }
+
+        #[test]
+        fn test_hex_to_ulid_non_hex_errors() {
+            assert!(hex_to_ulid("zz5549f01d7e3a66a08c07ce13d25363", Span::test_data()).is_err());
+        }
+
+        #[test]
+        fn test_little_endian_bytes_need_reversing() {
+            let span = Span::test_data();
+            let mut bytes = hex::decode("015549f01d7e3a66a08c07ce13d25363").unwrap();
+            bytes.reverse();
+            let little_endian = hex::encode(&bytes);
+            assert_ne!(
+                hex_to_ulid(&little_endian, span).unwrap(),
+                "01AN4Z07BY79KA1307SR9X4MV3"
+            );
+
+            bytes.reverse();
+            assert_eq!(
+                hex_to_ulid(&hex::encode(&bytes), span).unwrap(),
+                "01AN4Z07BY79KA1307SR9X4MV3"
+            );
+        }
+    }
+
+    mod base64_commands {
+        use super::*;
+
+        const BINARY: &[u8] = &[0xfb, 0xff, 0x00, 0x10, 0x7e, 0x3f, 0xbf];
+
+        #[test]
+        fn test_command_signatures() {
+            let sig = UlidEncodeBase64Command.signature();
+            assert_eq!(sig.name, "ulid encode base64");
+            assert!(sig.named.iter().any(|f| f.long == "url-safe"));
+            assert!(sig.named.iter().any(|f| f.long == "no-pad"));
+
+            let sig = UlidDecodeBase64Command.signature();
+            assert_eq!(sig.name, "ulid decode base64");
+            assert!(sig.named.iter().any(|f| f.long == "url-safe"));
+            assert!(sig.named.iter().any(|f| f.long == "text"));
+        }
+
+        #[test]
+        fn test_standard_roundtrip() {
+            let encoded = encode_base64(BINARY, false, false);
+            assert_eq!(encoded, "+/8AEH4/vw==");
+            assert_eq!(
+                decode_base64(&encoded, false, Span::test_data()).unwrap(),
+                BINARY
+            );
+        }
+
+        #[test]
+        fn test_standard_no_pad_roundtrip() {
+            let encoded = encode_base64(BINARY, false, true);
+            assert_eq!(encoded, "+/8AEH4/vw");
+            assert_eq!(
+                decode_base64(&encoded, false, Span::test_data()).unwrap(),
+                BINARY
+            );
+        }
+
+        #[test]
+        fn test_url_safe_roundtrip() {
+            let encoded = encode_base64(BINARY, true, false);
+            assert_eq!(encoded, "-_8AEH4_vw");
+            assert_eq!(
+                decode_base64(&encoded, true, Span::test_data()).unwrap(),
+                BINARY
+            );
+            assert_eq!(
+                decode_base64("-_8AEH4_vw==", true, Span::test_data()).unwrap(),
+                BINARY
+            );
+        }
+
+        #[test]
+        fn test_alphabet_mismatch_errors() {
+            let span = Span::test_data();
+            assert!(decode_base64("-_8AEH4_vw", false, span).is_err());
+            assert!(decode_base64("+/8AEH4/vw", true, span).is_err());
+        }
+
+        #[test]
+        fn test_ulid_bytes_roundtrip() {
+            let ulid = UlidEngine::generate().unwrap();
+            let bytes = UlidEngine::to_bytes(&ulid);
+            let encoded = encode_base64(&bytes, true, false);
+            assert_eq!(
+                decode_base64(&encoded, true, Span::test_data()).unwrap(),
+                bytes
+            );
+        }
+    }
+
     mod ulid_to_bytes_command {
         use super::*;
 
@@ -433,4 +1356,164 @@ mod tests {
             assert_eq!(ulid, restored);
         }
     }
+
+    mod streaming_encode_tests {
+        use super::*;
+
+        /// Reader that hands out at most `step` bytes per call, like a chunked byte stream.
+        struct ChunkedReader {
+            data: Vec<u8>,
+            position: usize,
+            step: usize,
+        }
+
+        impl Read for ChunkedReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let count = buf
+                    .len()
+                    .min(self.step)
+                    .min(self.data.len() - self.position);
+                buf[..count].copy_from_slice(&self.data[self.position..self.position + count]);
+                self.position += count;
+                Ok(count)
+            }
+        }
+
+        /// Spans several stream chunks for every encoding, with a partial final group.
+        fn sample_data() -> Vec<u8> {
+            (0..100_003u32).map(|i| (i * 31 % 251) as u8).collect()
+        }
+
+        fn stream_encode(data: &[u8], encoding: Encoding) -> String {
+            let source = ChunkedReader {
+                data: data.to_vec(),
+                position: 0,
+                step: 7,
+            };
+            let mut output = String::new();
+            EncodingReader::new(source, encoding)
+                .read_to_string(&mut output)
+                .unwrap();
+            output
+        }
+
+        #[test]
+        fn test_base32_stream_matches_one_shot() {
+            let data = sample_data();
+            let streamed = stream_encode(&data, Encoding::Base32);
+            assert_eq!(streamed, base32::encode(base32::Alphabet::Crockford, &data));
+        }
+
+        #[test]
+        fn test_hex_stream_matches_one_shot() {
+            let data = sample_data();
+            let streamed = stream_encode(&data, Encoding::Hex { uppercase: true });
+            assert_eq!(streamed, hex::encode_upper(&data));
+        }
+
+        #[test]
+        fn test_base64_stream_pads_only_at_end() {
+            let data = sample_data();
+            let encoding = Encoding::Base64 {
+                url_safe: false,
+                no_pad: false,
+            };
+            let streamed = stream_encode(&data, encoding);
+            assert_eq!(streamed, encode_base64(&data, false, false));
+            assert!(!streamed.trim_end_matches('=').contains('='));
+        }
+
+        #[test]
+        fn test_empty_stream_encodes_to_empty_string() {
+            let encoding = Encoding::Base64 {
+                url_safe: false,
+                no_pad: false,
+            };
+            assert!(stream_encode(&[], encoding).is_empty());
+        }
+    }
+
+    mod strict_base32_tests {
+        use super::*;
+
+        #[test]
+        fn test_signature_has_strict() {
+            let sig = UlidDecodeBase32Command.signature();
+            assert!(sig.named.iter().any(|f| f.long == "strict"));
+        }
+
+        #[test]
+        fn test_canonical_input_passes() {
+            assert!(check_canonical_base32("CSQPYRK1E8", Span::test_data()).is_ok());
+            assert!(
+                check_canonical_base32(crate::CROCKFORD_BASE32_CHARSET, Span::test_data()).is_ok()
+            );
+        }
+
+        #[test]
+        fn test_ambiguous_characters_rejected_under_strict() {
+            for input in ["CSQPYRKIE8", "CSQPYRKLE8", "CSQPYRK1EO", "csqpyrk1e8"] {
+                assert!(check_canonical_base32(input, Span::test_data()).is_err());
+            }
+        }
+
+        #[test]
+        fn test_ambiguous_characters_decoded_when_lenient() {
+            let span = Span::test_data();
+            let expected = decode_str("CSQPYRK1E8", "base32", span).unwrap();
+            for input in ["CSQPYRKIE8", "CSQPYRKLE8", "csqpyrk1e8"] {
+                assert_eq!(decode_str(input, "base32", span).unwrap(), expected);
+            }
+        }
+
+        #[test]
+        fn test_error_names_offending_character() {
+            let error = check_canonical_base32("CSQPYRKIE8", Span::test_data()).unwrap_err();
+            assert!(error.labels[0].text.contains("'I' at position 7"));
+        }
+    }
+
+    mod base32_as_ulid_tests {
+        use super::*;
+
+        const ULID: &str = "01AN4Z07BY79KA1307SR9X4MV3";
+
+        #[test]
+        fn test_signature_has_as_ulid() {
+            let sig = UlidDecodeBase32Command.signature();
+            assert!(sig.named.iter().any(|f| f.long == "as-ulid"));
+        }
+
+        #[test]
+        fn test_plain_decode_of_ulid_gives_16_bytes() {
+            let decoded = decode_str(ULID, "base32", Span::test_data()).unwrap();
+            assert_eq!(decoded.len(), crate::ULID_BYTE_LENGTH);
+        }
+
+        #[test]
+        fn test_as_ulid_returns_canonical_string() {
+            let span = Span::test_data();
+            for input in [
+                ULID,
+                "01an4z07by79ka1307sr9x4mv3",
+                "01AN4Z07BY79KAl3O7SR9X4MV3",
+            ] {
+                assert_eq!(base32_to_ulid(input, span).unwrap(), ULID);
+            }
+        }
+
+        #[test]
+        fn test_as_ulid_rejects_wrong_length() {
+            let span = Span::test_data();
+            assert!(base32_to_ulid("CSQPYRK1E8", span).is_err());
+            assert!(base32_to_ulid(&format!("{}0", ULID), span).is_err());
+        }
+
+        #[test]
+        fn test_as_ulid_rejects_overflow_and_invalid_characters() {
+            let span = Span::test_data();
+            assert!(base32_to_ulid("81AN4Z07BY79KA1307SR9X4MV3", span).is_err());
+            assert!(base32_to_ulid("01AN4Z07BY79KA1307SR9X4MVU", span).is_err());
+        }
+    }
 }
@@ -0,0 +1,215 @@
+//! ULID explanation command for learning how ULIDs are structured.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use crate::{
+    ULID_RANDOMNESS_CHARS, ULID_STRING_LENGTH, ULID_TIMESTAMP_CHARS, UlidComponents, UlidEngine,
+    UlidPlugin,
+};
+
+/// Walks through the parts of a ULID in plain language.
+pub struct UlidExplainCommand;
+
+impl PluginCommand for UlidExplainCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid explain"
+    }
+
+    fn description(&self) -> &str {
+        "Explain which parts of a ULID hold the timestamp and randomness, and what they mean"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("ulid", SyntaxShape::String, "The ULID to explain")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![].into()))])
+            .category(Category::Strings)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid explain '01AN4Z07BY79KA1307SR9X4MV3'",
+                description: "Explain the structure of a ULID",
+                result: None,
+            },
+            Example {
+                example: "ulid generate | ulid explain $in",
+                description: "Explain a freshly generated ULID",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let ulid_str: String = call.req(0)?;
+
+        let components = UlidEngine::parse(&ulid_str)
+            .map_err(|e| LabeledError::new("Parse failed").with_label(e.to_string(), call.head))?;
+
+        Ok(PipelineData::Value(
+            build_explanation(&components, call.head),
+            None,
+        ))
+    }
+}
+
+fn build_explanation(components: &UlidComponents, span: Span) -> Value {
+    let (timestamp_chars, randomness_chars) = components.ulid.split_at(ULID_TIMESTAMP_CHARS);
+
+    let decoded = chrono::DateTime::from_timestamp_millis(components.timestamp_ms as i64)
+        .map(|datetime| datetime.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+        .unwrap_or_else(|| "out of range".to_string());
+
+    let mut timestamp = Record::new();
+    timestamp.push(
+        "characters",
+        Value::string(format!("1-{}", ULID_TIMESTAMP_CHARS), span),
+    );
+    timestamp.push("text", Value::string(timestamp_chars, span));
+    timestamp.push("bits", Value::int(ulid::Ulid::TIME_BITS as i64, span));
+    timestamp.push(
+        "milliseconds",
+        Value::int(components.timestamp_ms as i64, span),
+    );
+    timestamp.push("decoded", Value::string(&decoded, span));
+    timestamp.push(
+        "meaning",
+        Value::string(
+            format!(
+                "The first {} characters are a Crockford Base32 count of milliseconds since the Unix epoch: {}",
+                ULID_TIMESTAMP_CHARS, decoded
+            ),
+            span,
+        ),
+    );
+
+    let mut randomness = Record::new();
+    randomness.push(
+        "characters",
+        Value::string(
+            format!("{}-{}", ULID_TIMESTAMP_CHARS + 1, ULID_STRING_LENGTH),
+            span,
+        ),
+    );
+    randomness.push("text", Value::string(randomness_chars, span));
+    randomness.push("bits", Value::int(ulid::Ulid::RAND_BITS as i64, span));
+    randomness.push(
+        "meaning",
+        Value::string(
+            format!(
+                "The last {} characters are random, so ULIDs created in the same millisecond are still unique",
+                ULID_RANDOMNESS_CHARS
+            ),
+            span,
+        ),
+    );
+
+    let mut record = Record::new();
+    record.push("ulid", Value::string(&components.ulid, span));
+    record.push("timestamp", Value::record(timestamp, span));
+    record.push("randomness", Value::record(randomness, span));
+    record.push(
+        "sortability",
+        Value::string(
+            "The timestamp comes first, so sorting ULIDs as text sorts them by creation time. \
+             Order within the same millisecond is random unless a monotonic generator is used.",
+            span,
+        ),
+    );
+
+    Value::record(record, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    #[test]
+    fn test_command_signature() {
+        let sig = UlidExplainCommand.signature();
+        assert_eq!(sig.name, "ulid explain");
+        assert_eq!(sig.required_positional.len(), 1);
+        assert_eq!(sig.required_positional[0].name, "ulid");
+    }
+
+    #[test]
+    fn test_command_examples_not_empty() {
+        assert!(!UlidExplainCommand.examples().is_empty());
+    }
+
+    #[test]
+    fn test_explanation_annotates_character_ranges() {
+        let components = UlidEngine::parse("01AN4Z07BY79KA1307SR9X4MV3").unwrap();
+        let explanation = build_explanation(&components, test_span());
+        let record = explanation.as_record().unwrap();
+
+        let timestamp = record.get("timestamp").unwrap().as_record().unwrap();
+        assert_eq!(
+            timestamp.get("characters").unwrap().as_str().unwrap(),
+            "1-10"
+        );
+        assert_eq!(
+            timestamp.get("text").unwrap().as_str().unwrap(),
+            "01AN4Z07BY"
+        );
+
+        let randomness = record.get("randomness").unwrap().as_record().unwrap();
+        assert_eq!(
+            randomness.get("characters").unwrap().as_str().unwrap(),
+            "11-26"
+        );
+        assert_eq!(
+            randomness.get("text").unwrap().as_str().unwrap(),
+            "79KA1307SR9X4MV3"
+        );
+        assert!(record.get("sortability").is_some());
+    }
+
+    #[test]
+    fn test_explanation_includes_decoded_time() {
+        let components = UlidEngine::parse("01AN4Z07BY79KA1307SR9X4MV3").unwrap();
+        let explanation = build_explanation(&components, test_span());
+        let timestamp = explanation
+            .as_record()
+            .unwrap()
+            .get("timestamp")
+            .unwrap()
+            .as_record()
+            .unwrap()
+            .clone();
+
+        assert_eq!(
+            timestamp.get("milliseconds").unwrap().as_int().unwrap(),
+            1465824320894
+        );
+        assert_eq!(
+            timestamp.get("decoded").unwrap().as_str().unwrap(),
+            "2016-06-13T13:25:20.894Z"
+        );
+        assert!(
+            timestamp
+                .get("meaning")
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .contains("2016-06-13T13:25:20.894Z")
+        );
+    }
+}
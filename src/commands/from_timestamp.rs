@@ -0,0 +1,206 @@
+//! Command for building a ULID at an exact timestamp.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use super::time::timestamp_to_millis;
+use crate::{UlidEngine, UlidPlugin};
+
+/// Builds a ULID for a given time, with random, minimum, or maximum randomness.
+pub struct UlidFromTimestampCommand;
+
+impl PluginCommand for UlidFromTimestampCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid from-timestamp"
+    }
+
+    fn description(&self) -> &str {
+        "Build a ULID for an exact time, optionally as the lowest or highest ULID in that millisecond"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "timestamp",
+                SyntaxShape::Any,
+                "ISO 8601 or RFC 3339 string, Unix seconds or milliseconds, or a date",
+            )
+            .switch(
+                "min",
+                "Use all-zero randomness, giving the lowest ULID at this time",
+                None,
+            )
+            .switch(
+                "max",
+                "Use all-one randomness, giving the highest ULID at this time",
+                None,
+            )
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::Generators)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid from-timestamp '2024-01-01T00:00:00Z'",
+                description: "Generate a ULID for midnight on 1 January 2024",
+                result: None,
+            },
+            Example {
+                example: "ulid from-timestamp 1465824320894 --min",
+                description: "Get the lowest possible ULID for a millisecond timestamp",
+                result: Some(Value::string(
+                    "01AN4Z07BY0000000000000000",
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                example: "let from = ulid from-timestamp ((date now) - 1day) --min; let to = ulid from-timestamp (date now) --max; $events | where id >= $from and id <= $to",
+                description: "Select events whose ULID falls within the last day",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let timestamp: Value = call.req(0)?;
+        let min = call.has_flag("min")?;
+        let max = call.has_flag("max")?;
+
+        if min && max {
+            return Err(LabeledError::new("Conflicting flags")
+                .with_label("Use either --min or --max, not both", call.head));
+        }
+
+        let millis = timestamp_to_millis(Some(timestamp), call.head)?;
+        let ulid = ulid_at(millis, min, max, call.head)?;
+        Ok(PipelineData::Value(ulid, None))
+    }
+}
+
+fn ulid_at(millis: i64, min: bool, max: bool, span: Span) -> Result<Value, LabeledError> {
+    let timestamp_ms = u64::try_from(millis).map_err(|_| {
+        LabeledError::new("Timestamp out of range")
+            .with_label("ULID timestamps cannot be before the Unix epoch", span)
+    })?;
+
+    let (lowest, highest) = UlidEngine::bounds_at(timestamp_ms)
+        .map_err(|e| LabeledError::new("Timestamp out of range").with_label(e.to_string(), span))?;
+
+    let ulid = if min {
+        lowest
+    } else if max {
+        highest
+    } else {
+        UlidEngine::generate_with_timestamp(timestamp_ms)
+            .map_err(|e| LabeledError::new("Generation failed").with_label(e.to_string(), span))?
+    };
+
+    Ok(Value::string(ulid.to_string(), span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMESTAMP_MS: i64 = 1465824320894;
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    fn ulid_string(millis: i64, min: bool, max: bool) -> String {
+        ulid_at(millis, min, max, test_span())
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    mod from_timestamp_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidFromTimestampCommand.signature();
+            assert_eq!(sig.name, "ulid from-timestamp");
+            assert_eq!(sig.required_positional.len(), 1);
+            assert!(sig.named.iter().any(|flag| flag.long == "min"));
+            assert!(sig.named.iter().any(|flag| flag.long == "max"));
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidFromTimestampCommand.examples().is_empty());
+        }
+    }
+
+    mod ulid_at_tests {
+        use super::*;
+
+        #[test]
+        fn test_min_has_all_zero_randomness() {
+            assert_eq!(
+                ulid_string(TIMESTAMP_MS, true, false),
+                "01AN4Z07BY0000000000000000"
+            );
+        }
+
+        #[test]
+        fn test_max_has_all_one_randomness() {
+            assert_eq!(
+                ulid_string(TIMESTAMP_MS, false, true),
+                "01AN4Z07BYZZZZZZZZZZZZZZZZ"
+            );
+        }
+
+        #[test]
+        fn test_random_falls_between_min_and_max() {
+            let random = ulid_string(TIMESTAMP_MS, false, false);
+            assert_eq!(
+                UlidEngine::extract_timestamp(&random).unwrap(),
+                TIMESTAMP_MS as u64
+            );
+            assert!(random >= ulid_string(TIMESTAMP_MS, true, false));
+            assert!(random <= ulid_string(TIMESTAMP_MS, false, true));
+            assert_ne!(random, ulid_string(TIMESTAMP_MS, false, false));
+        }
+
+        #[test]
+        fn test_iso_string_timestamp() {
+            let span = test_span();
+            let millis =
+                timestamp_to_millis(Some(Value::string("2024-01-01T00:00:00Z", span)), span)
+                    .unwrap();
+            let ulid = ulid_string(millis, true, false);
+            assert_eq!(
+                UlidEngine::extract_timestamp(&ulid).unwrap(),
+                1_704_067_200_000
+            );
+        }
+
+        #[test]
+        fn test_out_of_range_timestamps_error() {
+            assert!(ulid_at(-1, false, false, test_span()).is_err());
+            assert!(
+                ulid_at(
+                    crate::MAX_ULID_TIMESTAMP_MS as i64 + 1,
+                    false,
+                    false,
+                    test_span()
+                )
+                .is_err()
+            );
+        }
+    }
+}
@@ -5,14 +5,16 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
 };
 
+use super::time::humanize_duration;
+use super::ulid::plausible_year_range;
 use crate::{UlidEngine, UlidPlugin};
 
 const ULID_TIMESTAMP_BITS: i64 = 48;
 const ULID_RANDOMNESS_BITS: i64 = 80;
 const ULID_TOTAL_BITS: i64 = 128;
-const SECONDS_PER_MINUTE: i64 = 60;
-const SECONDS_PER_HOUR: i64 = 3600;
-const SECONDS_PER_DAY: i64 = 86400;
+
+/// Default tolerance for `--compare-now` before a ULID is flagged as clock drift.
+const DEFAULT_CLOCK_SKEW_MS: i64 = 5000;
 
 /// Extracts detailed information and metadata from ULIDs.
 pub struct UlidInspectCommand;
@@ -38,6 +40,39 @@ impl PluginCommand for UlidInspectCommand {
                 Some('t'),
             )
             .switch("stats", "Include statistical information", Some('s'))
+            .switch(
+                "charmap",
+                "Include each character's position, part, and 5-bit Crockford Base32 value",
+                None,
+            )
+            .switch(
+                "warn-implausible",
+                "Add a timestamp_warning field when the timestamp year is outside the plausible window",
+                None,
+            )
+            .switch(
+                "compare-now",
+                "Add a drift record comparing the ULID timestamp to the current time",
+                None,
+            )
+            .named(
+                "skew-ms",
+                SyntaxShape::Int,
+                "Clock skew tolerance in milliseconds for --compare-now (default: 5000)",
+                None,
+            )
+            .named(
+                "min-year",
+                SyntaxShape::Int,
+                "Earliest plausible year for --warn-implausible (default: 2015)",
+                None,
+            )
+            .named(
+                "max-year",
+                SyntaxShape::Int,
+                "Latest plausible year for --warn-implausible (default: 2100)",
+                None,
+            )
             .input_output_types(vec![(Type::Nothing, Type::Record(vec![].into()))])
             .category(Category::Strings)
     }
@@ -64,6 +99,21 @@ impl PluginCommand for UlidInspectCommand {
                 description: "Include statistical analysis of the ULID",
                 result: None,
             },
+            Example {
+                example: "ulid inspect '01AN4Z07BY79KA1307SR9X4MV3' --charmap | get charmap",
+                description: "Show how each character of a ULID decodes",
+                result: None,
+            },
+            Example {
+                example: "ulid generate | ulid inspect $in --compare-now --skew-ms 1000",
+                description: "Check whether a ULID's timestamp is within 1 second of the local clock",
+                result: None,
+            },
+            Example {
+                example: "ulid inspect '01AN4Z07BY79KA1307SR9X4MV3' --warn-implausible --min-year 2020",
+                description: "Flag ULIDs whose timestamp falls before 2020",
+                result: None,
+            },
         ]
     }
 
@@ -78,14 +128,17 @@ impl PluginCommand for UlidInspectCommand {
         let compact: bool = call.has_flag("compact")?;
         let timestamp_only: bool = call.has_flag("timestamp-only")?;
         let stats: bool = call.has_flag("stats")?;
-
-        if !UlidEngine::validate(&ulid_str) {
-            return Err(LabeledError::new("Invalid ULID")
-                .with_label(format!("'{}' is not a valid ULID", ulid_str), call.head));
+        let charmap: bool = call.has_flag("charmap")?;
+        let plausible_years = plausible_year_range(call)?;
+        let compare_now: bool = call.has_flag("compare-now")?;
+        let skew_ms: i64 = call.get_flag("skew-ms")?.unwrap_or(DEFAULT_CLOCK_SKEW_MS);
+
+        if skew_ms < 0 {
+            return Err(LabeledError::new("Invalid skew")
+                .with_label("--skew-ms must not be negative", call.head));
         }
 
-        let components = UlidEngine::parse(&ulid_str)
-            .map_err(|e| LabeledError::new("Parse failed").with_label(e.to_string(), call.head))?;
+        let components = parse_inspected_ulid(&ulid_str, call.head)?;
 
         let mut record = nu_protocol::Record::new();
 
@@ -98,6 +151,21 @@ impl PluginCommand for UlidInspectCommand {
             record.push("timestamp", ts_value);
         }
 
+        let warning = plausible_years.and_then(|(min_year, max_year)| {
+            UlidEngine::timestamp_warning(components.timestamp_ms, min_year, max_year)
+        });
+        if let Some(warning) = warning {
+            record.push("timestamp_warning", Value::string(warning, call.head));
+        }
+
+        if compare_now {
+            let now_ms = chrono::Utc::now().timestamp_millis();
+            record.push(
+                "drift",
+                build_drift_record(components.timestamp_ms, now_ms, skew_ms, call.head),
+            );
+        }
+
         if !timestamp_only {
             record.push(
                 "randomness",
@@ -109,10 +177,28 @@ impl PluginCommand for UlidInspectCommand {
             record.push("statistics", build_stats_record(&components, call.head));
         }
 
+        if charmap && !timestamp_only {
+            let ulid = components.ulid.parse::<ulid::Ulid>().map_err(|e| {
+                LabeledError::new("Parse failed").with_label(e.to_string(), call.head)
+            })?;
+            record.push("charmap", build_charmap(ulid, call.head));
+        }
+
         Ok(PipelineData::Value(Value::record(record, call.head), None))
     }
 }
 
+/// Parses the ULID once, treating a parse failure as the input being invalid.
+fn parse_inspected_ulid(
+    ulid_str: &str,
+    span: nu_protocol::Span,
+) -> Result<crate::UlidComponents, LabeledError> {
+    UlidEngine::parse(ulid_str).map_err(|_| {
+        LabeledError::new("Invalid ULID")
+            .with_label(format!("'{}' is not a valid ULID", ulid_str), span)
+    })
+}
+
 fn build_timestamp_value(
     components: &crate::UlidComponents,
     compact: bool,
@@ -199,6 +285,18 @@ fn build_stats_record(components: &crate::UlidComponents, span: nu_protocol::Spa
     let randomness_entropy = analyze_entropy(&components.randomness_hex);
     stats_record.push("randomness_entropy", Value::float(randomness_entropy, span));
 
+    // Uppercase so lowercase input is measured the same as its canonical form
+    let canonical = components.ulid.to_ascii_uppercase();
+    let randomness_chars = &canonical[crate::ULID_TIMESTAMP_CHARS..];
+    stats_record.push(
+        "randomness_max_run",
+        Value::int(longest_run(randomness_chars) as i64, span),
+    );
+    stats_record.push(
+        "randomness_distinct_chars",
+        Value::int(distinct_chars(randomness_chars) as i64, span),
+    );
+
     stats_record.push(
         "collision_probability_per_ms",
         Value::string("~1 in 1.2 × 10^24".to_string(), span),
@@ -207,21 +305,80 @@ fn build_stats_record(components: &crate::UlidComponents, span: nu_protocol::Spa
     Value::record(stats_record, span)
 }
 
+fn build_drift_record(
+    timestamp_ms: u64,
+    now_ms: i64,
+    skew_ms: i64,
+    span: nu_protocol::Span,
+) -> Value {
+    let milliseconds_ago = now_ms - timestamp_ms as i64;
+
+    let mut drift_record = nu_protocol::Record::new();
+    drift_record.push("milliseconds_ago", Value::int(milliseconds_ago, span));
+    drift_record.push("is_future", Value::bool(milliseconds_ago < 0, span));
+    drift_record.push(
+        "within_clock_skew",
+        Value::bool(milliseconds_ago.abs() <= skew_ms, span),
+    );
+
+    Value::record(drift_record, span)
+}
+
+/// Lists each character of a canonical ULID string with its position and 5-bit value.
+///
+/// Built from the canonical string, so lowercase input is shown as the uppercase characters
+/// it decodes to. Concatenating the `bits` fields gives 130 bits; the leading 2 are always
+/// zero, which is why the first character can be no higher than `7`.
+fn build_charmap(ulid: ulid::Ulid, span: nu_protocol::Span) -> Value {
+    let bits = u128::from(ulid);
+    let last = crate::ULID_STRING_LENGTH - 1;
+
+    let entries = ulid
+        .to_string()
+        .chars()
+        .enumerate()
+        .map(|(position, c)| {
+            let value = (bits >> (5 * (last - position))) & 0x1f;
+            let part = if position < crate::ULID_TIMESTAMP_CHARS {
+                "timestamp"
+            } else {
+                "randomness"
+            };
+
+            let mut entry = nu_protocol::Record::new();
+            entry.push("position", Value::int(position as i64, span));
+            entry.push("char", Value::string(c.to_string(), span));
+            entry.push("part", Value::string(part, span));
+            entry.push("value", Value::int(value as i64, span));
+            entry.push("bits", Value::string(format!("{:05b}", value), span));
+            Value::record(entry, span)
+        })
+        .collect();
+
+    Value::list(entries, span)
+}
+
 fn format_duration(duration: chrono::Duration) -> String {
-    let total_seconds = duration.num_seconds();
-
-    if total_seconds < SECONDS_PER_MINUTE {
-        format!("{} seconds ago", total_seconds)
-    } else if total_seconds < SECONDS_PER_HOUR {
-        let minutes = total_seconds / SECONDS_PER_MINUTE;
-        format!("{} minutes ago", minutes)
-    } else if total_seconds < SECONDS_PER_DAY {
-        let hours = total_seconds / SECONDS_PER_HOUR;
-        format!("{} hours ago", hours)
-    } else {
-        let days = total_seconds / SECONDS_PER_DAY;
-        format!("{} days ago", days)
+    format!("{} ago", humanize_duration(duration))
+}
+
+/// Length of the longest run of identical consecutive characters.
+fn longest_run(text: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous = None;
+
+    for ch in text.chars() {
+        current = if previous == Some(ch) { current + 1 } else { 1 };
+        longest = longest.max(current);
+        previous = Some(ch);
     }
+
+    longest
+}
+
+fn distinct_chars(text: &str) -> usize {
+    text.chars().collect::<std::collections::HashSet<_>>().len()
 }
 
 fn analyze_entropy(hex_string: &str) -> f64 {
@@ -270,6 +427,11 @@ mod tests {
             assert!(sig.named.iter().any(|f| f.long == "compact"));
             assert!(sig.named.iter().any(|f| f.long == "timestamp-only"));
             assert!(sig.named.iter().any(|f| f.long == "stats"));
+            assert!(sig.named.iter().any(|f| f.long == "warn-implausible"));
+            assert!(sig.named.iter().any(|f| f.long == "min-year"));
+            assert!(sig.named.iter().any(|f| f.long == "max-year"));
+            assert!(sig.named.iter().any(|f| f.long == "compare-now"));
+            assert!(sig.named.iter().any(|f| f.long == "skew-ms"));
         }
 
         #[test]
@@ -283,6 +445,31 @@ mod tests {
         }
     }
 
+    mod parse_inspected_ulid_tests {
+        use super::*;
+
+        #[test]
+        fn test_valid_ulid_parses() {
+            let components =
+                parse_inspected_ulid("01AN4Z07BY79KA1307SR9X4MV3", test_span()).unwrap();
+            assert_eq!(components.ulid, test_components().ulid);
+            assert_eq!(components.timestamp_ms, test_components().timestamp_ms);
+            assert!(components.valid);
+        }
+
+        #[test]
+        fn test_invalid_ulid_reports_invalid() {
+            for input in [
+                "invalid",
+                "01AN4Z07BY79KA1307SR9X4MV",
+                "01AN4Z07BY79KA1307SR9X4MVU",
+            ] {
+                let error = parse_inspected_ulid(input, test_span()).unwrap_err();
+                assert_eq!(error.msg, "Invalid ULID");
+            }
+        }
+    }
+
     mod build_timestamp_value_tests {
         use super::*;
 
@@ -371,10 +558,145 @@ mod tests {
                     );
                     assert!(val.get("randomness_entropy").is_some());
                     assert!(val.get("collision_probability_per_ms").is_some());
+                    assert!(val.get("randomness_max_run").is_some());
+                    assert!(val.get("randomness_distinct_chars").is_some());
                 }
                 _ => panic!("Expected record value"),
             }
         }
+
+        #[test]
+        fn test_crafted_uniform_randomness() {
+            let ulid = ulid::Ulid::from_parts(1465824320894, 0).to_string();
+            let components = crate::UlidEngine::parse(&ulid).unwrap();
+            let result = build_stats_record(&components, test_span());
+            let record = result.as_record().unwrap();
+            assert_eq!(
+                record.get("randomness_max_run").unwrap().as_int().unwrap(),
+                16
+            );
+            assert_eq!(
+                record
+                    .get("randomness_distinct_chars")
+                    .unwrap()
+                    .as_int()
+                    .unwrap(),
+                1
+            );
+        }
+    }
+
+    mod build_drift_record_tests {
+        use super::*;
+
+        const NOW_MS: i64 = 1_700_000_000_000;
+
+        fn drift(timestamp_ms: u64) -> nu_protocol::Record {
+            build_drift_record(timestamp_ms, NOW_MS, DEFAULT_CLOCK_SKEW_MS, test_span())
+                .into_record()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_recent_ulid_is_within_skew() {
+            let record = drift(NOW_MS as u64 - 1_200);
+            assert_eq!(
+                record.get("milliseconds_ago").unwrap().as_int().unwrap(),
+                1_200
+            );
+            assert!(!record.get("is_future").unwrap().as_bool().unwrap());
+            assert!(record.get("within_clock_skew").unwrap().as_bool().unwrap());
+        }
+
+        #[test]
+        fn test_far_past_ulid_is_outside_skew() {
+            let components = test_components();
+            let record = drift(components.timestamp_ms);
+            assert!(!record.get("is_future").unwrap().as_bool().unwrap());
+            assert!(!record.get("within_clock_skew").unwrap().as_bool().unwrap());
+        }
+
+        #[test]
+        fn test_future_ulid_within_skew() {
+            let record = drift(NOW_MS as u64 + 3_000);
+            assert_eq!(
+                record.get("milliseconds_ago").unwrap().as_int().unwrap(),
+                -3_000
+            );
+            assert!(record.get("is_future").unwrap().as_bool().unwrap());
+            assert!(record.get("within_clock_skew").unwrap().as_bool().unwrap());
+        }
+    }
+
+    mod build_charmap_tests {
+        use super::*;
+
+        fn charmap_entries() -> Vec<Value> {
+            charmap_of("01AN4Z07BY79KA1307SR9X4MV3")
+        }
+
+        fn charmap_of(ulid_str: &str) -> Vec<Value> {
+            build_charmap(ulid_str.parse().unwrap(), test_span())
+                .into_list()
+                .unwrap()
+        }
+
+        fn field<'a>(entry: &'a Value, name: &str) -> &'a Value {
+            entry.as_record().unwrap().get(name).unwrap()
+        }
+
+        #[test]
+        fn test_has_one_entry_per_character_in_order() {
+            let entries = charmap_entries();
+            assert_eq!(entries.len(), crate::ULID_STRING_LENGTH);
+            for (index, entry) in entries.iter().enumerate() {
+                assert_eq!(field(entry, "position").as_int().unwrap(), index as i64);
+            }
+        }
+
+        #[test]
+        fn test_lowercase_input_maps_canonical_characters() {
+            let lower = charmap_of("01an4z07by79ka1307sr9x4mv3");
+            assert_eq!(lower, charmap_entries());
+            assert_eq!(field(&lower[2], "char").as_str().unwrap(), "A");
+            assert_eq!(field(&lower[2], "value").as_int().unwrap(), 10);
+        }
+
+        #[test]
+        fn test_values_and_parts() {
+            let entries = charmap_entries();
+
+            // "01AN4Z07BY79KA1307SR9X4MV3": 'A' is 10, 'Z' is 31
+            assert_eq!(field(&entries[2], "char").as_str().unwrap(), "A");
+            assert_eq!(field(&entries[2], "value").as_int().unwrap(), 10);
+            assert_eq!(field(&entries[5], "value").as_int().unwrap(), 31);
+            assert_eq!(field(&entries[5], "bits").as_str().unwrap(), "11111");
+
+            for entry in &entries {
+                let value = field(entry, "value").as_int().unwrap() as usize;
+                assert_eq!(
+                    &crate::CROCKFORD_BASE32_CHARSET[value..=value],
+                    field(entry, "char").as_str().unwrap()
+                );
+            }
+
+            assert_eq!(field(&entries[9], "part").as_str().unwrap(), "timestamp");
+            assert_eq!(field(&entries[10], "part").as_str().unwrap(), "randomness");
+        }
+
+        #[test]
+        fn test_bits_reassemble_the_ulid() {
+            let bits: String = charmap_entries()
+                .iter()
+                .map(|entry| field(entry, "bits").as_str().unwrap().to_string())
+                .collect();
+            assert_eq!(bits.len(), 130);
+            assert!(bits.starts_with("00"));
+
+            let value = u128::from_str_radix(&bits[2..], 2).unwrap();
+            let ulid: ulid::Ulid = test_components().ulid.parse().unwrap();
+            assert_eq!(value, u128::from(ulid));
+        }
     }
 
     mod format_duration_tests {
@@ -403,6 +725,30 @@ mod tests {
             let d = chrono::Duration::seconds(172800);
             assert_eq!(format_duration(d), "2 days ago");
         }
+
+        #[test]
+        fn test_years_and_months() {
+            let d = chrono::Duration::days(835);
+            assert_eq!(format_duration(d), "2 years 3 months ago");
+        }
+    }
+
+    mod structural_metrics_tests {
+        use super::*;
+
+        #[test]
+        fn test_longest_run() {
+            assert_eq!(longest_run(""), 0);
+            assert_eq!(longest_run("ABCD"), 1);
+            assert_eq!(longest_run("AABBBC"), 3);
+            assert_eq!(longest_run("79KA1307SR9X4MV3"), 1);
+        }
+
+        #[test]
+        fn test_distinct_chars() {
+            assert_eq!(distinct_chars("0000"), 1);
+            assert_eq!(distinct_chars("79KA1307SR9X4MV3"), 13);
+        }
     }
 
     mod analyze_entropy_tests {
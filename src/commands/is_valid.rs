@@ -0,0 +1,233 @@
+//! List-oriented ULID validity predicate.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use super::ulid::is_valid_ulid_value;
+use crate::UlidPlugin;
+
+/// Checks ULID validity for every element of its pipeline input.
+///
+/// Unlike `ulid validate`, which takes a single positional ULID, this reads from the pipeline
+/// and maps element by element: a list gives a list of booleans in the same order and a
+/// scalar gives a single boolean. Values that are not strings or binaries are reported as
+/// invalid rather than raising an error.
+pub struct UlidIsValidCommand;
+
+impl PluginCommand for UlidIsValidCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid is-valid"
+    }
+
+    fn description(&self) -> &str {
+        "Check whether each piped value is a valid ULID, returning a boolean per element"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .named(
+                "column",
+                SyntaxShape::String,
+                "Check the ULID in this column of each record",
+                Some('c'),
+            )
+            .input_output_types(vec![
+                (Type::String, Type::Bool),
+                (Type::Binary, Type::Bool),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Bool)),
+                ),
+            ])
+            .category(Category::Strings)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "'01AN4Z07BY79KA1307SR9X4MV3' | ulid is-valid",
+                description: "Check a single ULID",
+                result: Some(Value::bool(true, Span::test_data())),
+            },
+            Example {
+                example: r#"["01AN4Z07BY79KA1307SR9X4MV3", "invalid"] | ulid is-valid"#,
+                description: "Check each ULID in a list",
+                result: Some(Value::list(
+                    vec![
+                        Value::bool(true, Span::test_data()),
+                        Value::bool(false, Span::test_data()),
+                    ],
+                    Span::test_data(),
+                )),
+            },
+            Example {
+                example: "open events.csv | merge ($in | ulid is-valid --column id | wrap id_valid)",
+                description: "Add a column flagging rows whose ID is a valid ULID",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let column: Option<String> = call.get_flag("column")?;
+
+        match input {
+            PipelineData::Value(Value::List { vals, .. }, _) => {
+                let results = vals
+                    .iter()
+                    .map(|value| check_value(value, column.as_deref(), call.head))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(PipelineData::Value(Value::list(results, call.head), None))
+            }
+            PipelineData::ListStream(stream, _) => {
+                let span = stream.span();
+                let results = stream.into_iter().map(move |value| {
+                    check_value(&value, column.as_deref(), span)
+                        .unwrap_or_else(|e| Value::error(e.into(), span))
+                });
+                Ok(PipelineData::ListStream(
+                    ListStream::new(results, span, engine.signals().clone()),
+                    None,
+                ))
+            }
+            PipelineData::Value(value, _) => Ok(PipelineData::Value(
+                check_value(&value, column.as_deref(), call.head)?,
+                None,
+            )),
+            PipelineData::Empty => Err(LabeledError::new("Missing input").with_label(
+                "Pipe a ULID or a list of ULIDs into this command",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a ULID or a list of ULIDs", call.head)),
+        }
+    }
+}
+
+/// Returns whether `value`, or its `column` when given, holds a valid ULID.
+///
+/// A missing column is an error rather than `false`, so a misspelt column name is not
+/// mistaken for a column of invalid IDs.
+fn check_value(value: &Value, column: Option<&str>, span: Span) -> Result<Value, LabeledError> {
+    let ulid_value = match column {
+        Some(column) => match value {
+            Value::Record { val, .. } => val.get(column).ok_or_else(|| {
+                LabeledError::new("Missing column")
+                    .with_label(format!("Record has no '{}' column", column), span)
+            })?,
+            _ => {
+                return Err(LabeledError::new("Invalid input").with_label(
+                    format!("Expected a record with a '{}' column", column),
+                    span,
+                ));
+            }
+        },
+        None => value,
+    };
+
+    Ok(Value::bool(is_valid_ulid_value(ulid_value), span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Record;
+
+    const VALID: &str = "01AN4Z07BY79KA1307SR9X4MV3";
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    fn check(value: Value, column: Option<&str>) -> bool {
+        check_value(&value, column, test_span())
+            .unwrap()
+            .as_bool()
+            .unwrap()
+    }
+
+    fn record_with_id(id: &str) -> Value {
+        let mut record = Record::new();
+        record.push("id", Value::string(id, test_span()));
+        Value::record(record, test_span())
+    }
+
+    mod is_valid_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidIsValidCommand.signature();
+            assert_eq!(sig.name, "ulid is-valid");
+            assert!(sig.required_positional.is_empty());
+            assert!(sig.named.iter().any(|f| f.long == "column"));
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidIsValidCommand.examples().is_empty());
+        }
+    }
+
+    mod check_value_tests {
+        use super::*;
+
+        #[test]
+        fn test_scalar_input() {
+            assert!(check(Value::string(VALID, test_span()), None));
+            assert!(!check(Value::string("invalid", test_span()), None));
+        }
+
+        #[test]
+        fn test_binary_input() {
+            let ulid = crate::UlidEngine::generate().unwrap();
+            let bytes = crate::UlidEngine::to_bytes(&ulid);
+            assert!(check(Value::binary(bytes, test_span()), None));
+            assert!(!check(Value::binary(vec![0u8; 4], test_span()), None));
+        }
+
+        #[test]
+        fn test_list_input_keeps_order() {
+            let results: Vec<bool> = [VALID, "invalid", VALID]
+                .iter()
+                .map(|id| check(Value::string(*id, test_span()), None))
+                .collect();
+            assert_eq!(results, vec![true, false, true]);
+        }
+
+        #[test]
+        fn test_non_string_values_are_invalid() {
+            assert!(!check(Value::int(42, test_span()), None));
+            assert!(!check(Value::nothing(test_span()), None));
+        }
+
+        #[test]
+        fn test_column_of_records() {
+            assert!(check(record_with_id(VALID), Some("id")));
+            assert!(!check(record_with_id("invalid"), Some("id")));
+        }
+
+        #[test]
+        fn test_missing_column_errors() {
+            let result = check_value(&record_with_id(VALID), Some("uid"), test_span());
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_column_on_non_record_errors() {
+            let value = Value::string(VALID, test_span());
+            assert!(check_value(&value, Some("id"), test_span()).is_err());
+        }
+    }
+}
@@ -1,20 +1,36 @@
 //! Command implementations for the ULID plugin.
 
+pub mod convert;
+pub mod downsample;
 pub mod encode;
+pub mod explain;
+pub mod from_timestamp;
 pub mod info;
 pub mod inspect;
+pub mod is_valid;
 pub mod sort;
+pub mod stats;
 pub mod time;
 pub mod ulid;
+pub mod verify_order;
 
+pub use convert::UlidConvertCommand;
+pub use downsample::UlidDownsampleCommand;
 pub use encode::{
-    UlidDecodeBase32Command, UlidDecodeHexCommand, UlidEncodeBase32Command, UlidEncodeHexCommand,
+    UlidDecodeBase32Command, UlidDecodeBase64Command, UlidDecodeCommand, UlidDecodeHexCommand,
+    UlidEncodeBase32Command, UlidEncodeBase64Command, UlidEncodeCommand, UlidEncodeHexCommand,
     UlidToBytesCommand,
 };
+pub use explain::UlidExplainCommand;
+pub use from_timestamp::UlidFromTimestampCommand;
 pub use info::UlidInfoCommand;
 pub use inspect::UlidInspectCommand;
+pub use is_valid::UlidIsValidCommand;
 pub use sort::UlidSortCommand;
+pub use stats::UlidStatsCommand;
 pub use time::{UlidTimeMillisCommand, UlidTimeNowCommand, UlidTimeParseCommand};
 pub use ulid::{
     UlidGenerateCommand, UlidParseCommand, UlidSecurityAdviceCommand, UlidValidateCommand,
+    UlidValidateSummaryCommand,
 };
+pub use verify_order::UlidVerifyOrderCommand;
@@ -1,10 +1,16 @@
 //! ULID sorting command.
 
 use std::cmp::Ordering;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Lines, Write};
+use std::iter::Peekable;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
-    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+    Category, Example, LabeledError, ListStream, PipelineData, Record, Signals, Signature, Span,
+    SyntaxShape, Type, Value,
 };
 
 use crate::{UlidEngine, UlidPlugin};
@@ -31,6 +37,12 @@ impl PluginCommand for UlidSortCommand {
                 "Column containing ULIDs to sort by",
                 Some('c'),
             )
+            .named(
+                "numeric-column",
+                SyntaxShape::String,
+                "Integer timestamp column to sort by instead of parsing ULIDs",
+                None,
+            )
             .switch(
                 "reverse",
                 "Sort in descending order (newest first)",
@@ -41,6 +53,18 @@ impl PluginCommand for UlidSortCommand {
                 "Use natural ULID string sorting instead of timestamp",
                 Some('n'),
             )
+            .named(
+                "chunk-size",
+                SyntaxShape::Int,
+                "Sort streamed strings or records of strings and ints in chunks of this many items, spilling to temp files and merging",
+                None,
+            )
+            .named(
+                "missing",
+                SyntaxShape::String,
+                "Placement of records missing the sort column: first, last, or error",
+                Some('m'),
+            )
             .input_output_types(vec![
                 (
                     Type::List(Box::new(Type::String)),
@@ -76,19 +100,76 @@ impl PluginCommand for UlidSortCommand {
                 description: "Sort ULIDs using natural string ordering",
                 result: None,
             },
+            Example {
+                example: r#"[{id: "01AN4Z07BZ79KA1307SR9X4MV4", created_ms: 1465824320895}, {id: "01AN4Z07BY79KA1307SR9X4MV3", created_ms: 1465824320894}] | ulid sort --numeric-column created_ms"#,
+                description: "Sort records by a pre-extracted millisecond timestamp column",
+                result: None,
+            },
+            Example {
+                example: "open --raw ids.txt | lines | ulid sort --chunk-size 1000000",
+                description: "Sort a stream too large for memory using an external merge sort",
+                result: None,
+            },
+            Example {
+                example: r#"[{id: "01AN4Z07BZ79KA1307SR9X4MV4"}, {name: "no id"}] | ulid sort --column id --reverse --missing last"#,
+                description: "Keep records without an id at the end, even in descending order",
+                result: None,
+            },
         ]
     }
 
     fn run(
         &self,
         _plugin: &Self::Plugin,
-        _engine: &EngineInterface,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let column: Option<String> = call.get_flag("column")?;
         let reverse: bool = call.has_flag("reverse")?;
         let natural: bool = call.has_flag("natural")?;
+        let numeric_column: Option<String> = call.get_flag("numeric-column")?;
+        let chunk_size: Option<i64> = call.get_flag("chunk-size")?;
+        let missing: Option<String> = call.get_flag("missing")?;
+
+        if column.is_some() && numeric_column.is_some() {
+            return Err(LabeledError::new("Conflicting flags").with_label(
+                "Use either --column or --numeric-column, not both",
+                call.head,
+            ));
+        }
+
+        if let Some(policy) = &missing {
+            if !matches!(policy.as_str(), "first" | "last" | "error") {
+                return Err(LabeledError::new("Invalid missing policy").with_label(
+                    format!(
+                        "Unknown --missing policy '{}'. Valid policies: first, last, error",
+                        policy
+                    ),
+                    call.head,
+                ));
+            }
+            if column.is_none() && numeric_column.is_none() {
+                return Err(LabeledError::new("Missing sort column")
+                    .with_label("--missing requires --column or --numeric-column", call.head));
+            }
+        }
+
+        let chunk_size = match chunk_size {
+            Some(size) if size <= 0 => {
+                return Err(LabeledError::new("Invalid chunk size")
+                    .with_label("--chunk-size must be greater than zero", call.head));
+            }
+            size => size.map(|size| size as usize),
+        };
+
+        let order = SortOrder {
+            column,
+            numeric_column,
+            natural,
+            reverse,
+            missing,
+        };
 
         match input {
             PipelineData::Value(
@@ -99,24 +180,39 @@ impl PluginCommand for UlidSortCommand {
                 },
                 _,
             ) => {
-                let mut sorted_vals = vals;
-
-                // Sort based on whether we have a column specified
-                if let Some(col_name) = column {
-                    // Sort records by ULID in specified column
-                    sorted_vals.sort_by(|a, b| {
-                        compare_records_by_column(a, b, &col_name, natural, reverse)
-                    });
-                } else {
-                    // Sort list of ULID strings directly
-                    sorted_vals.sort_by(|a, b| compare_ulid_values(a, b, natural, reverse));
+                if chunk_size.is_some() {
+                    return Err(LabeledError::new("Chunk size needs streamed input").with_label(
+                        "--chunk-size only applies to streamed input; this list is already in memory",
+                        call.head,
+                    ));
                 }
+                order.check_missing(&vals, 0, call.head)?;
+                let mut sorted_vals = vals;
+                sorted_vals.sort_by(|a, b| order.compare(a, b));
 
                 Ok(PipelineData::Value(
                     Value::list(sorted_vals, internal_span),
                     None,
                 ))
             }
+            PipelineData::ListStream(stream, _) => {
+                let span = stream.span();
+                match chunk_size {
+                    Some(chunk_size) => external_sort(
+                        stream.into_iter(),
+                        chunk_size,
+                        order,
+                        engine.signals().clone(),
+                        span,
+                    ),
+                    None => {
+                        let mut sorted_vals: Vec<Value> = stream.into_iter().collect();
+                        order.check_missing(&sorted_vals, 0, call.head)?;
+                        sorted_vals.sort_by(|a, b| order.compare(a, b));
+                        Ok(PipelineData::Value(Value::list(sorted_vals, span), None))
+                    }
+                }
+            }
             PipelineData::Empty => Ok(PipelineData::Empty),
             _ => Err(LabeledError::new("Invalid input").with_label(
                 "Expected a list of ULIDs or records containing ULIDs",
@@ -126,71 +222,466 @@ impl PluginCommand for UlidSortCommand {
     }
 }
 
-fn compare_records_by_column(
-    a: &Value,
-    b: &Value,
-    column: &str,
+/// The ordering selected by the `ulid sort` flags.
+struct SortOrder {
+    column: Option<String>,
+    numeric_column: Option<String>,
     natural: bool,
     reverse: bool,
-) -> Ordering {
-    let a_ulid = extract_ulid_from_record(a, column);
-    let b_ulid = extract_ulid_from_record(b, column);
-
-    match (a_ulid, b_ulid) {
-        (Some(a_str), Some(b_str)) => {
-            let ordering = compare_ulid_strings(&a_str, &b_str, natural);
-            if reverse {
-                ordering.reverse()
-            } else {
-                ordering
-            }
+    missing: Option<String>,
+}
+
+impl SortOrder {
+    fn compare(&self, a: &Value, b: &Value) -> Ordering {
+        if let Some(ordering) = self.compare_missing(a, b) {
+            return ordering;
         }
-        (Some(_), None) => {
-            if reverse {
-                Ordering::Greater
-            } else {
-                Ordering::Less
-            }
+
+        if let Some(col_name) = &self.numeric_column {
+            // Sort records by an existing integer timestamp, skipping ULID parsing
+            compare_records_by_numeric_column(a, b, col_name, self.reverse)
+        } else if let Some(col_name) = &self.column {
+            // Sort records by ULID in specified column
+            compare_records_by_column(a, b, col_name, self.natural, self.reverse)
+        } else {
+            // Sort list of ULID strings directly
+            compare_ulid_values(a, b, self.natural, self.reverse)
         }
-        (None, Some(_)) => {
-            if reverse {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
+    }
+
+    fn sort_column(&self) -> Option<&str> {
+        self.numeric_column.as_deref().or(self.column.as_deref())
+    }
+
+    /// Orders records missing the sort column under `--missing first|last`.
+    ///
+    /// The placement is absolute, so `--reverse` does not move them. Returns `None` when
+    /// both records have the column and the regular comparison applies.
+    fn compare_missing(&self, a: &Value, b: &Value) -> Option<Ordering> {
+        let missing_first = match self.missing.as_deref() {
+            Some("first") => true,
+            Some("last") => false,
+            _ => return None,
+        };
+        let column = self.sort_column()?;
+
+        match (lacks_column(a, column), lacks_column(b, column)) {
+            (false, false) => None,
+            (true, true) => Some(Ordering::Equal),
+            (true, false) if missing_first => Some(Ordering::Less),
+            (true, false) => Some(Ordering::Greater),
+            (false, true) if missing_first => Some(Ordering::Greater),
+            (false, true) => Some(Ordering::Less),
+        }
+    }
+
+    /// Fails on the first record missing the sort column under `--missing error`.
+    ///
+    /// `offset` is the position of `values` within the whole input, for the error message.
+    fn check_missing(
+        &self,
+        values: &[Value],
+        offset: usize,
+        span: Span,
+    ) -> Result<(), LabeledError> {
+        if self.missing.as_deref() != Some("error") {
+            return Ok(());
+        }
+        let Some(column) = self.sort_column() else {
+            return Ok(());
+        };
+
+        match values.iter().position(|value| lacks_column(value, column)) {
+            Some(index) => Err(LabeledError::new("Missing sort column").with_label(
+                format!("Item {} has no '{}' column", offset + index, column),
+                span,
+            )),
+            None => Ok(()),
         }
-        (None, None) => Ordering::Equal,
     }
 }
 
-fn compare_ulid_values(a: &Value, b: &Value, natural: bool, reverse: bool) -> Ordering {
-    let a_str = extract_string_value(a);
-    let b_str = extract_string_value(b);
-
-    match (a_str, b_str) {
-        (Some(a_ulid), Some(b_ulid)) => {
-            let ordering = compare_ulid_strings(&a_ulid, &b_ulid, natural);
-            if reverse {
-                ordering.reverse()
-            } else {
-                ordering
+/// Sorts `values` in chunks of `chunk_size`, spilling each sorted chunk to a temp file
+/// and k-way merging the files into an output stream.
+///
+/// Input that fits in a single chunk is sorted in memory and never touches disk. The
+/// merge holds one value per chunk in memory and scans the chunk heads linearly, taking
+/// the first minimum so the overall sort stays stable like the in-memory path.
+///
+/// Every item is checked with [`is_spillable`] before its chunk is sorted, whether or not
+/// the chunk is later spilled, so the chunk size never changes which inputs are accepted.
+fn external_sort(
+    values: impl Iterator<Item = Value>,
+    chunk_size: usize,
+    order: SortOrder,
+    signals: Signals,
+    span: Span,
+) -> Result<PipelineData, LabeledError> {
+    let mut values = values.peekable();
+    let mut spills = Vec::new();
+
+    loop {
+        let mut chunk = next_chunk(&mut values, chunk_size);
+        let offset = spills.len() * chunk_size;
+        check_spillable(&chunk, offset, span)?;
+        order.check_missing(&chunk, offset, span)?;
+        chunk.sort_by(|a, b| order.compare(a, b));
+
+        if spills.is_empty() && values.peek().is_none() {
+            return Ok(PipelineData::Value(Value::list(chunk, span), None));
+        }
+
+        spills.push(SpillFile::write(&chunk).map_err(|e| spill_failed(e, span))?);
+        if values.peek().is_none() {
+            break;
+        }
+    }
+
+    let merge = ChunkMerge::new(spills, order, span).map_err(|e| spill_failed(e, span))?;
+    Ok(PipelineData::ListStream(
+        ListStream::new(merge, span, signals),
+        None,
+    ))
+}
+
+fn spill_failed(error: io::Error, span: Span) -> LabeledError {
+    LabeledError::new("Sort failed")
+        .with_label(format!("Failed to spill sorted chunk: {}", error), span)
+}
+
+fn next_chunk(values: &mut Peekable<impl Iterator<Item = Value>>, chunk_size: usize) -> Vec<Value> {
+    values.by_ref().take(chunk_size).collect()
+}
+
+/// Fails on the first item that cannot be written to a spill file without loss.
+///
+/// `offset` is the position of `values` within the whole input, for the error message.
+fn check_spillable(values: &[Value], offset: usize, span: Span) -> Result<(), LabeledError> {
+    match values.iter().position(|value| !is_spillable(value)) {
+        Some(index) => Err(LabeledError::new("Unsupported value")
+            .with_label(
+                format!(
+                    "Item {} is a {}, which --chunk-size cannot spill to disk",
+                    offset + index,
+                    values[index].get_type()
+                ),
+                span,
+            )
+            .with_help(
+                "--chunk-size supports strings and records whose fields are strings or ints",
+            )),
+        None => Ok(()),
+    }
+}
+
+/// Whether a value round-trips exactly through [`encode_spilled`] and [`decode_spilled`].
+fn is_spillable(value: &Value) -> bool {
+    encode_spilled(value).is_some()
+}
+
+/// Encodes a value as a single line, or returns `None` if it is not [`is_spillable`].
+///
+/// A string is `s` followed by a tab and the escaped text. A record is `r` followed by a tab,
+/// the escaped column name, a tab, and a type-tagged field (`s` or `i` then the value) for
+/// each column.
+fn encode_spilled(value: &Value) -> Option<String> {
+    match value {
+        Value::String { val, .. } => Some(format!("s\t{}", escape_spilled(val))),
+        Value::Record { val, .. } => {
+            let mut line = String::from("r");
+            for (column, field) in val.iter() {
+                line.push('\t');
+                line.push_str(&escape_spilled(column));
+                line.push('\t');
+                match field {
+                    Value::String { val, .. } => {
+                        line.push('s');
+                        line.push_str(&escape_spilled(val));
+                    }
+                    Value::Int { val, .. } => {
+                        line.push('i');
+                        line.push_str(&val.to_string());
+                    }
+                    _ => return None,
+                }
             }
+            Some(line)
         }
-        (Some(_), None) => {
-            if reverse {
-                Ordering::Greater
-            } else {
-                Ordering::Less
+        _ => None,
+    }
+}
+
+fn decode_spilled(line: &str, span: Span) -> Option<Value> {
+    let mut parts = line.split('\t');
+    match parts.next()? {
+        "s" => Some(Value::string(unescape_spilled(parts.next()?), span)),
+        "r" => {
+            let mut record = Record::new();
+            while let Some(column) = parts.next() {
+                let field = parts.next()?;
+                let value = match field.split_at_checked(1)? {
+                    ("i", int) => Value::int(int.parse().ok()?, span),
+                    ("s", text) => Value::string(unescape_spilled(text), span),
+                    _ => return None,
+                };
+                record.push(unescape_spilled(column), value);
             }
+            Some(Value::record(record, span))
+        }
+        _ => None,
+    }
+}
+
+fn escape_spilled(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+fn unescape_spilled(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// A sorted chunk written to a temp file, one [`encode_spilled`] line per value.
+///
+/// [`ChunkMerge`] removes the file once the merge finishes and reports any failure in the
+/// output stream. If the merge is abandoned early, for example on Ctrl-C, the file is
+/// removed when the spill is dropped instead.
+struct SpillFile {
+    path: PathBuf,
+    removed: bool,
+}
+
+impl SpillFile {
+    fn write(chunk: &[Value]) -> io::Result<Self> {
+        static SPILL_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "nu_plugin_nw_ulid-sort-{}-{}.spill",
+            std::process::id(),
+            SPILL_COUNTER.fetch_add(1, AtomicOrdering::Relaxed)
+        ));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        let spill = Self {
+            path,
+            removed: false,
+        };
+
+        let mut writer = BufWriter::new(file);
+        for value in chunk {
+            let line = encode_spilled(value).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "value cannot be spilled")
+            })?;
+            writer.write_all(line.as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+
+        Ok(spill)
+    }
+
+    fn open(&self) -> io::Result<Lines<BufReader<File>>> {
+        Ok(BufReader::new(File::open(&self.path)?).lines())
+    }
+
+    fn remove(mut self) -> io::Result<()> {
+        self.removed = true;
+        fs::remove_file(&self.path)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if !self.removed {
+            // Drop cannot report errors; the normal path goes through `remove` instead
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Streams the k-way merge of sorted spill files.
+struct ChunkMerge {
+    // Readers are declared before the spills so file handles close before removal.
+    readers: Vec<Lines<BufReader<File>>>,
+    heads: Vec<Option<Value>>,
+    pending_error: Option<Value>,
+    order: SortOrder,
+    span: Span,
+    spills: Vec<SpillFile>,
+}
+
+impl ChunkMerge {
+    fn new(spills: Vec<SpillFile>, order: SortOrder, span: Span) -> io::Result<Self> {
+        let readers = spills
+            .iter()
+            .map(SpillFile::open)
+            .collect::<io::Result<Vec<_>>>()?;
+        let mut merge = Self {
+            heads: vec![None; readers.len()],
+            readers,
+            pending_error: None,
+            order,
+            span,
+            spills,
+        };
+        for index in 0..merge.readers.len() {
+            merge.advance(index);
         }
-        (None, Some(_)) => {
-            if reverse {
-                Ordering::Less
-            } else {
-                Ordering::Greater
+        Ok(merge)
+    }
+
+    fn sort_error(&self, message: String) -> Value {
+        Value::error(
+            LabeledError::new("Sort failed")
+                .with_label(message, self.span)
+                .into(),
+            self.span,
+        )
+    }
+
+    /// Loads the next value from chunk `index` into its head slot.
+    ///
+    /// A read failure ends that chunk and queues an error value for the output stream.
+    fn advance(&mut self, index: usize) {
+        self.heads[index] = match self.readers[index].next() {
+            None => None,
+            Some(line) => match line.map_err(|e| e.to_string()).and_then(|line| {
+                decode_spilled(&line, self.span).ok_or_else(|| "malformed spill line".to_string())
+            }) {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    self.pending_error =
+                        Some(self.sort_error(format!("Failed to read sorted chunk: {}", e)));
+                    None
+                }
+            },
+        };
+    }
+
+    /// Closes and removes the spill files, returning the first removal failure.
+    fn finish(&mut self) -> Option<Value> {
+        self.readers.clear();
+        let mut first_error = None;
+        for spill in self.spills.drain(..) {
+            let path = spill.path.display().to_string();
+            if let Err(e) = spill.remove() {
+                first_error.get_or_insert_with(|| {
+                    format!("Failed to remove sort spill file '{}': {}", path, e)
+                });
             }
         }
+        first_error.map(|message| self.sort_error(message))
+    }
+}
+
+impl Iterator for ChunkMerge {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if let Some(error) = self.pending_error.take() {
+            return Some(error);
+        }
+
+        let Some(index) = self
+            .heads
+            .iter()
+            .enumerate()
+            .filter_map(|(index, head)| head.as_ref().map(|value| (index, value)))
+            .min_by(|(_, a), (_, b)| self.order.compare(a, b))
+            .map(|(index, _)| index)
+        else {
+            return self.finish();
+        };
+
+        let value = self.heads[index].take();
+        self.advance(index);
+        value
+    }
+}
+
+fn compare_records_by_column(
+    a: &Value,
+    b: &Value,
+    column: &str,
+    natural: bool,
+    reverse: bool,
+) -> Ordering {
+    compare_optional(
+        extract_ulid_from_record(a, column),
+        extract_ulid_from_record(b, column),
+        reverse,
+        |a, b| compare_ulid_strings(a, b, natural),
+    )
+}
+
+fn compare_records_by_numeric_column(
+    a: &Value,
+    b: &Value,
+    column: &str,
+    reverse: bool,
+) -> Ordering {
+    compare_optional(
+        extract_int_from_record(a, column),
+        extract_int_from_record(b, column),
+        reverse,
+        |a, b| a.cmp(b),
+    )
+}
+
+fn compare_ulid_values(a: &Value, b: &Value, natural: bool, reverse: bool) -> Ordering {
+    compare_optional(
+        extract_string_value(a),
+        extract_string_value(b),
+        reverse,
+        |a, b| compare_ulid_strings(a, b, natural),
+    )
+}
+
+/// Orders two optional sort keys with `compare`, placing present keys before missing ones.
+///
+/// `reverse` flips the whole ordering, so missing keys come first in descending order.
+fn compare_optional<T>(
+    a: Option<T>,
+    b: Option<T>,
+    reverse: bool,
+    compare: impl FnOnce(&T, &T) -> Ordering,
+) -> Ordering {
+    let ordering = match (a, b) {
+        (Some(a), Some(b)) => compare(&a, &b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
         (None, None) => Ordering::Equal,
+    };
+    if reverse {
+        ordering.reverse()
+    } else {
+        ordering
     }
 }
 
@@ -232,6 +723,20 @@ fn extract_ulid_from_record(value: &Value, column: &str) -> Option<String> {
     }
 }
 
+fn lacks_column(value: &Value, column: &str) -> bool {
+    match value {
+        Value::Record { val, .. } => val.get(column).is_none(),
+        _ => true,
+    }
+}
+
+fn extract_int_from_record(value: &Value, column: &str) -> Option<i64> {
+    match value {
+        Value::Record { val, .. } => val.get(column).and_then(|v| v.as_int().ok()),
+        _ => None,
+    }
+}
+
 fn extract_string_value(value: &Value) -> Option<String> {
     match value {
         Value::String { val, .. } => Some(val.clone()),
@@ -259,6 +764,9 @@ mod tests {
             assert!(sig.named.iter().any(|f| f.long == "column"));
             assert!(sig.named.iter().any(|f| f.long == "reverse"));
             assert!(sig.named.iter().any(|f| f.long == "natural"));
+            assert!(sig.named.iter().any(|f| f.long == "numeric-column"));
+            assert!(sig.named.iter().any(|f| f.long == "chunk-size"));
+            assert!(sig.named.iter().any(|f| f.long == "missing"));
         }
 
         #[test]
@@ -298,6 +806,68 @@ mod tests {
             let a = "01AN4Z07BY79KA1307SR9X4MV3";
             assert_eq!(compare_ulid_strings(a, a, false), Ordering::Equal);
         }
+
+        #[test]
+        fn test_compare_optional_places_missing_keys_last() {
+            let cmp = |a: Option<i64>, b: Option<i64>, reverse| {
+                compare_optional(a, b, reverse, |a, b| a.cmp(b))
+            };
+            assert_eq!(cmp(Some(1), Some(2), false), Ordering::Less);
+            assert_eq!(cmp(Some(1), None, false), Ordering::Less);
+            assert_eq!(cmp(None, Some(1), false), Ordering::Greater);
+            assert_eq!(cmp(None, None, false), Ordering::Equal);
+
+            assert_eq!(cmp(Some(1), Some(2), true), Ordering::Greater);
+            assert_eq!(cmp(Some(1), None, true), Ordering::Greater);
+            assert_eq!(cmp(None, None, true), Ordering::Equal);
+        }
+    }
+
+    mod numeric_column_tests {
+        use super::*;
+
+        fn record(id: &str, created_ms: i64) -> Value {
+            let mut record = nu_protocol::Record::new();
+            record.push("id", Value::string(id, test_span()));
+            record.push("created_ms", Value::int(created_ms, test_span()));
+            Value::record(record, test_span())
+        }
+
+        fn sorted_timestamps(mut vals: Vec<Value>, reverse: bool) -> Vec<i64> {
+            vals.sort_by(|a, b| compare_records_by_numeric_column(a, b, "created_ms", reverse));
+            vals.iter()
+                .map(|v| extract_int_from_record(v, "created_ms").unwrap())
+                .collect()
+        }
+
+        fn unsorted() -> Vec<Value> {
+            vec![record("c", 3_000), record("a", 1_000), record("b", 2_000)]
+        }
+
+        #[test]
+        fn test_sorts_ascending() {
+            assert_eq!(
+                sorted_timestamps(unsorted(), false),
+                vec![1_000, 2_000, 3_000]
+            );
+        }
+
+        #[test]
+        fn test_sorts_descending_with_reverse() {
+            assert_eq!(
+                sorted_timestamps(unsorted(), true),
+                vec![3_000, 2_000, 1_000]
+            );
+        }
+
+        #[test]
+        fn test_missing_values_sort_last() {
+            let mut vals = unsorted();
+            vals.insert(0, Value::string("not a record", test_span()));
+            vals.sort_by(|a, b| compare_records_by_numeric_column(a, b, "created_ms", false));
+            assert_eq!(extract_int_from_record(&vals[0], "created_ms"), Some(1_000));
+            assert_eq!(extract_int_from_record(&vals[3], "created_ms"), None);
+        }
     }
 
     mod extract_helpers {
@@ -333,4 +903,305 @@ mod tests {
             assert_eq!(extract_ulid_from_record(&val, "id"), None);
         }
     }
+
+    mod external_sort_tests {
+        use super::*;
+
+        fn natural_order() -> SortOrder {
+            SortOrder {
+                column: None,
+                numeric_column: None,
+                natural: true,
+                reverse: false,
+                missing: None,
+            }
+        }
+
+        fn shuffled_ulids(count: u64) -> Vec<Value> {
+            (0..count)
+                .map(|i| {
+                    let timestamp_ms = 1_700_000_000_000 + (i * 7919) % count;
+                    let ulid = ulid::Ulid::from_parts(timestamp_ms, u128::from(i));
+                    Value::string(ulid.to_string(), test_span())
+                })
+                .collect()
+        }
+
+        fn sort_to_vec(vals: Vec<Value>, chunk_size: usize, order: SortOrder) -> Vec<Value> {
+            let result = external_sort(
+                vals.into_iter(),
+                chunk_size,
+                order,
+                Signals::empty(),
+                test_span(),
+            )
+            .unwrap();
+            result
+                .into_value(test_span())
+                .unwrap()
+                .as_list()
+                .unwrap()
+                .to_vec()
+        }
+
+        #[test]
+        fn test_large_stream_with_small_chunks_is_fully_sorted() {
+            let vals = shuffled_ulids(1000);
+            let mut expected: Vec<String> = vals
+                .iter()
+                .map(|v| v.as_str().unwrap().to_string())
+                .collect();
+            expected.sort();
+
+            let sorted: Vec<String> = sort_to_vec(vals, 64, natural_order())
+                .iter()
+                .map(|v| v.as_str().unwrap().to_string())
+                .collect();
+
+            assert_eq!(sorted, expected);
+        }
+
+        #[test]
+        fn test_input_within_one_chunk_stays_in_memory() {
+            let result = external_sort(
+                shuffled_ulids(10).into_iter(),
+                10,
+                natural_order(),
+                Signals::empty(),
+                test_span(),
+            )
+            .unwrap();
+            assert!(matches!(result, PipelineData::Value(Value::List { .. }, _)));
+        }
+
+        #[test]
+        fn test_records_merge_in_reverse_numeric_order() {
+            let vals: Vec<Value> = (0..200)
+                .map(|i| {
+                    let mut record = nu_protocol::Record::new();
+                    record.push("created_ms", Value::int((i * 37) % 200, test_span()));
+                    Value::record(record, test_span())
+                })
+                .collect();
+            let order = SortOrder {
+                column: None,
+                numeric_column: Some("created_ms".to_string()),
+                natural: false,
+                reverse: true,
+                missing: None,
+            };
+
+            let sorted: Vec<i64> = sort_to_vec(vals, 16, order)
+                .iter()
+                .map(|v| extract_int_from_record(v, "created_ms").unwrap())
+                .collect();
+
+            assert_eq!(sorted, (0..200).rev().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn test_spilled_strings_round_trip_exactly() {
+            for text in [
+                "",
+                "plain",
+                "tab\there",
+                "line\nbreak\r\n",
+                "back\\slash\\t",
+            ] {
+                let value = Value::string(text, test_span());
+                let line = encode_spilled(&value).unwrap();
+                assert!(!line.contains('\n'), "{:?}", text);
+                assert_eq!(decode_spilled(&line, test_span()), Some(value));
+            }
+        }
+
+        #[test]
+        fn test_spilled_records_keep_field_types() {
+            let mut record = nu_protocol::Record::new();
+            record.push(
+                "id",
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", test_span()),
+            );
+            record.push("count", Value::int(-42, test_span()));
+            record.push("label\tname", Value::string("7", test_span()));
+            let value = Value::record(record, test_span());
+
+            let line = encode_spilled(&value).unwrap();
+            assert_eq!(decode_spilled(&line, test_span()), Some(value));
+        }
+
+        #[test]
+        fn test_unsupported_value_is_rejected_with_overall_index() {
+            let mut vals = shuffled_ulids(10);
+            vals.push(Value::float(1.5, test_span()));
+            let err = external_sort(
+                vals.into_iter(),
+                4,
+                natural_order(),
+                Signals::empty(),
+                test_span(),
+            )
+            .unwrap_err();
+            assert_eq!(err.msg, "Unsupported value");
+            assert!(err.labels[0].text.contains("Item 10"));
+        }
+
+        #[test]
+        fn test_nested_record_is_rejected_even_within_one_chunk() {
+            let mut inner = nu_protocol::Record::new();
+            inner.push(
+                "id",
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", test_span()),
+            );
+            let mut outer = nu_protocol::Record::new();
+            outer.push("inner", Value::record(inner, test_span()));
+
+            let vals = vec![Value::record(outer, test_span())];
+            let result = external_sort(
+                vals.into_iter(),
+                10,
+                natural_order(),
+                Signals::empty(),
+                test_span(),
+            );
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_spill_files_are_removed_after_merge() {
+            let first = SpillFile::write(&shuffled_ulids(3)).unwrap();
+            let second = SpillFile::write(&shuffled_ulids(3)).unwrap();
+            let paths = vec![first.path.clone(), second.path.clone()];
+
+            let merge = ChunkMerge::new(vec![first, second], natural_order(), test_span()).unwrap();
+            let merged: Vec<Value> = merge.collect();
+
+            assert_eq!(merged.len(), 6);
+            assert!(merged.iter().all(|v| !v.is_error()));
+            assert!(paths.iter().all(|path| !path.exists()));
+        }
+    }
+
+    mod missing_column_tests {
+        use super::*;
+
+        const EARLY: &str = "01AN4Z07BY79KA1307SR9X4MV3";
+        const LATE: &str = "01BN4Z07BY79KA1307SR9X4MV3";
+
+        fn with_id(id: &str) -> Value {
+            let mut record = nu_protocol::Record::new();
+            record.push("id", Value::string(id, test_span()));
+            Value::record(record, test_span())
+        }
+
+        fn without_id(name: &str) -> Value {
+            let mut record = nu_protocol::Record::new();
+            record.push("name", Value::string(name, test_span()));
+            Value::record(record, test_span())
+        }
+
+        fn records() -> Vec<Value> {
+            vec![
+                without_id("a"),
+                with_id(LATE),
+                without_id("b"),
+                with_id(EARLY),
+            ]
+        }
+
+        fn order(missing: Option<&str>, reverse: bool) -> SortOrder {
+            SortOrder {
+                column: Some("id".to_string()),
+                numeric_column: None,
+                natural: false,
+                reverse,
+                missing: missing.map(str::to_string),
+            }
+        }
+
+        /// Sorts the records and labels each by its id, or by name when the id is missing.
+        fn sorted_labels(order: &SortOrder) -> Vec<String> {
+            let mut vals = records();
+            vals.sort_by(|a, b| order.compare(a, b));
+            vals.iter()
+                .map(|v| {
+                    let record = v.as_record().unwrap();
+                    record
+                        .get("id")
+                        .or_else(|| record.get("name"))
+                        .and_then(|v| v.as_str().ok())
+                        .unwrap()
+                        .to_string()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_missing_first() {
+            assert_eq!(
+                sorted_labels(&order(Some("first"), false)),
+                vec!["a", "b", EARLY, LATE]
+            );
+        }
+
+        #[test]
+        fn test_missing_last() {
+            assert_eq!(
+                sorted_labels(&order(Some("last"), false)),
+                vec![EARLY, LATE, "a", "b"]
+            );
+        }
+
+        #[test]
+        fn test_missing_placement_ignores_reverse() {
+            assert_eq!(
+                sorted_labels(&order(Some("last"), true)),
+                vec![LATE, EARLY, "a", "b"]
+            );
+            assert_eq!(
+                sorted_labels(&order(Some("first"), true)),
+                vec!["a", "b", LATE, EARLY]
+            );
+        }
+
+        #[test]
+        fn test_default_keeps_missing_after_in_sort_direction() {
+            assert_eq!(sorted_labels(&order(None, false))[2..], ["a", "b"]);
+            assert_eq!(sorted_labels(&order(None, true))[..2], ["a", "b"]);
+        }
+
+        #[test]
+        fn test_missing_error_reports_first_missing_item() {
+            let err = order(Some("error"), false)
+                .check_missing(&records(), 0, test_span())
+                .unwrap_err();
+            assert_eq!(err.msg, "Missing sort column");
+            assert!(err.labels[0].text.contains("Item 0"));
+        }
+
+        #[test]
+        fn test_missing_error_passes_complete_records() {
+            let vals = vec![with_id(LATE), with_id(EARLY)];
+            assert!(
+                order(Some("error"), false)
+                    .check_missing(&vals, 0, test_span())
+                    .is_ok()
+            );
+        }
+
+        #[test]
+        fn test_missing_error_in_external_sort_reports_overall_index() {
+            let mut vals: Vec<Value> = (0..10).map(|_| with_id(EARLY)).collect();
+            vals.push(without_id("late"));
+            let err = external_sort(
+                vals.into_iter(),
+                4,
+                order(Some("error"), false),
+                Signals::empty(),
+                test_span(),
+            )
+            .unwrap_err();
+            assert!(err.labels[0].text.contains("Item 10"));
+        }
+    }
 }
@@ -0,0 +1,198 @@
+//! ULID timestamp statistics command.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::{UlidEngine, UlidPlugin};
+
+/// Summarises the timestamp distribution of a list of ULIDs.
+///
+/// Percentiles use the nearest-rank method over the sorted timestamps, so the
+/// cost is `O(n log n)` in the number of ULIDs and `O(n)` extra memory.
+pub struct UlidStatsCommand;
+
+impl PluginCommand for UlidStatsCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid stats"
+    }
+
+    fn description(&self) -> &str {
+        "Summarise the timestamp distribution of a list of ULIDs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::String)),
+                Type::Record(vec![].into()),
+            )])
+            .category(Category::Math)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "ulid generate --count 100 | ulid stats",
+                description: "Profile the timestamps of a list of ULIDs",
+                result: None,
+            },
+            Example {
+                example: "open events.csv | get id | ulid stats",
+                description: "Profile the time distribution of an ID column",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let vals = match input.into_value(call.head)? {
+            Value::List { vals, .. } => vals,
+            _ => {
+                return Err(LabeledError::new("Invalid input")
+                    .with_label("Expected a list of ULIDs", call.head));
+            }
+        };
+
+        let timestamps = extract_timestamps(&vals, call.head)?;
+        let record = build_stats_record(timestamps, call.head)?;
+        Ok(PipelineData::Value(record, None))
+    }
+}
+
+fn extract_timestamps(vals: &[Value], span: Span) -> Result<Vec<u64>, LabeledError> {
+    vals.iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let ulid_str = value.as_str().map_err(|_| {
+                LabeledError::new("Invalid input")
+                    .with_label(format!("Item {} is not a string", index), span)
+            })?;
+            UlidEngine::extract_timestamp(ulid_str).map_err(|e| {
+                LabeledError::new("Invalid ULID").with_label(format!("Item {}: {}", index, e), span)
+            })
+        })
+        .collect()
+}
+
+fn build_stats_record(mut timestamps: Vec<u64>, span: Span) -> Result<Value, LabeledError> {
+    if timestamps.is_empty() {
+        return Err(LabeledError::new("Empty input").with_label("Expected at least one ULID", span));
+    }
+
+    timestamps.sort_unstable();
+
+    let min = timestamps[0];
+    let max = timestamps[timestamps.len() - 1];
+
+    let mut record = Record::new();
+    record.push("count", Value::int(timestamps.len() as i64, span));
+    record.push("min_timestamp", Value::int(min as i64, span));
+    record.push("max_timestamp", Value::int(max as i64, span));
+    record.push("span_ms", Value::int((max - min) as i64, span));
+    record.push("p50", Value::int(percentile(&timestamps, 50) as i64, span));
+    record.push("p90", Value::int(percentile(&timestamps, 90) as i64, span));
+    record.push("p99", Value::int(percentile(&timestamps, 99) as i64, span));
+
+    Ok(Value::record(record, span))
+}
+
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    mod stats_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidStatsCommand.signature();
+            assert_eq!(sig.name, "ulid stats");
+            assert!(sig.required_positional.is_empty());
+        }
+
+        #[test]
+        fn test_command_name() {
+            assert_eq!(UlidStatsCommand.name(), "ulid stats");
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidStatsCommand.examples().is_empty());
+        }
+    }
+
+    mod build_stats_record_tests {
+        use super::*;
+
+        #[test]
+        fn test_known_set() {
+            let timestamps: Vec<u64> = (1..=100).rev().map(|i| 1_700_000_000_000 + i).collect();
+            let result = build_stats_record(timestamps, test_span()).unwrap();
+            let record = result.as_record().unwrap();
+
+            let get = |key: &str| record.get(key).unwrap().as_int().unwrap();
+            assert_eq!(get("count"), 100);
+            assert_eq!(get("min_timestamp"), 1_700_000_000_001);
+            assert_eq!(get("max_timestamp"), 1_700_000_000_100);
+            assert_eq!(get("span_ms"), 99);
+            assert_eq!(get("p50"), 1_700_000_000_050);
+            assert_eq!(get("p90"), 1_700_000_000_090);
+            assert_eq!(get("p99"), 1_700_000_000_099);
+        }
+
+        #[test]
+        fn test_single_timestamp() {
+            let result = build_stats_record(vec![42], test_span()).unwrap();
+            let record = result.as_record().unwrap();
+            assert_eq!(record.get("span_ms").unwrap().as_int().unwrap(), 0);
+            assert_eq!(record.get("p99").unwrap().as_int().unwrap(), 42);
+        }
+
+        #[test]
+        fn test_empty_input_errors() {
+            assert!(build_stats_record(Vec::new(), test_span()).is_err());
+        }
+    }
+
+    mod extract_timestamps_tests {
+        use super::*;
+
+        #[test]
+        fn test_extracts_from_ulids() {
+            let vals = vec![Value::string("01AN4Z07BY79KA1307SR9X4MV3", test_span())];
+            let timestamps = extract_timestamps(&vals, test_span()).unwrap();
+            assert_eq!(timestamps, vec![1465824320894]);
+        }
+
+        #[test]
+        fn test_invalid_item_errors() {
+            let vals = vec![
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", test_span()),
+                Value::string("invalid", test_span()),
+            ];
+            assert!(extract_timestamps(&vals, test_span()).is_err());
+
+            let vals = vec![Value::int(1, test_span())];
+            assert!(extract_timestamps(&vals, test_span()).is_err());
+        }
+    }
+}
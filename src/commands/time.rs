@@ -1,6 +1,6 @@
 //! Time utility commands for timestamp parsing and conversion.
 
-use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
     Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
@@ -9,6 +9,18 @@ use nu_protocol::{
 use crate::UlidPlugin;
 
 const TIMESTAMP_MILLIS_THRESHOLD: i64 = 1_000_000_000_000;
+/// Date-time formats without an offset, tried after RFC 3339 and RFC 2822 and read as UTC.
+const FALLBACK_DATETIME_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.3fZ", "%Y-%m-%d %H:%M:%S"];
+/// Date-only formats, tried last and read as midnight UTC.
+const FALLBACK_DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d"];
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 3600;
+const SECONDS_PER_DAY: i64 = 86400;
+const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+/// Average Gregorian year of 365.2425 days.
+const SECONDS_PER_YEAR: i64 = 31_556_952;
+/// One twelfth of an average Gregorian year, about 30.44 days.
+const SECONDS_PER_MONTH: i64 = SECONDS_PER_YEAR / 12;
 
 /// Gets the current timestamp in various formats.
 pub struct UlidTimeNowCommand;
@@ -32,7 +44,16 @@ impl PluginCommand for UlidTimeNowCommand {
                 "Output format: 'iso8601', 'rfc3339', 'millis', 'seconds'",
                 Some('f'),
             )
-            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .named(
+                "precision",
+                SyntaxShape::String,
+                "Return an integer epoch in 'ms', 'us', or 'ns', refining --format millis (ULIDs only store milliseconds)",
+                Some('p'),
+            )
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::Nothing, Type::Int),
+            ])
             .category(Category::Date)
     }
 
@@ -53,6 +74,11 @@ impl PluginCommand for UlidTimeNowCommand {
                 description: "Get current timestamp in seconds",
                 result: None,
             },
+            Example {
+                example: "ulid time now --precision ns",
+                description: "Get current timestamp in nanoseconds, finer than ULIDs can store",
+                result: None,
+            },
         ]
     }
 
@@ -64,30 +90,88 @@ impl PluginCommand for UlidTimeNowCommand {
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let format: Option<String> = call.get_flag("format")?;
-        let now = Utc::now();
-
-        let result = match format.as_deref() {
-            Some("millis") => Value::int(now.timestamp_millis(), call.head),
-            Some("seconds") => Value::int(now.timestamp(), call.head),
-            Some("rfc3339") => Value::string(now.to_rfc3339(), call.head),
-            Some("iso8601") | None => {
-                Value::string(now.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(), call.head)
-            }
-            Some(fmt) => {
-                return Err(LabeledError::new("Invalid format").with_label(
-                    format!(
-                        "Unknown format '{}'. Valid formats: iso8601, rfc3339, millis, seconds",
-                        fmt
-                    ),
-                    call.head,
-                ));
-            }
+        let precision: Option<String> = call.get_flag("precision")?;
+
+        let result = format_now(
+            Utc::now(),
+            format.as_deref(),
+            precision.as_deref(),
+            call.head,
+        )?;
+        Ok(PipelineData::Value(result, None))
+    }
+}
+
+/// Formats `now` for `ulid time now`.
+///
+/// `--precision` refines the millisecond epoch, so it combines only with `--format millis`
+/// or no format. With `--format seconds` the two flags ask for different units, and with a
+/// string format there is no epoch to refine, so both are errors.
+fn format_now(
+    now: DateTime<Utc>,
+    format: Option<&str>,
+    precision: Option<&str>,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(precision) = precision {
+        return match format {
+            Some("millis") | None => epoch_at_precision(now, precision, span),
+            Some("seconds") => Err(LabeledError::new("Conflicting flags")
+                .with_label("Use either --format seconds or --precision, not both", span)),
+            Some(_) => Err(LabeledError::new("Invalid precision").with_label(
+                "--precision applies only to the numeric format 'millis'",
+                span,
+            )),
         };
+    }
 
-        Ok(PipelineData::Value(result, None))
+    match format {
+        Some("millis") => Ok(Value::int(now.timestamp_millis(), span)),
+        Some("seconds") => Ok(Value::int(now.timestamp(), span)),
+        Some("rfc3339") => Ok(Value::string(now.to_rfc3339(), span)),
+        Some("iso8601") | None => Ok(Value::string(
+            now.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            span,
+        )),
+        Some(fmt) => Err(LabeledError::new("Invalid format").with_label(
+            format!(
+                "Unknown format '{}'. Valid formats: iso8601, rfc3339, millis, seconds",
+                fmt
+            ),
+            span,
+        )),
     }
 }
 
+/// Converts a date-time to an integer epoch count at the given precision.
+fn epoch_at_precision(
+    datetime: DateTime<Utc>,
+    precision: &str,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let epoch = match precision {
+        "ms" => datetime.timestamp_millis(),
+        "us" => datetime.timestamp_micros(),
+        "ns" => datetime.timestamp_nanos_opt().ok_or_else(|| {
+            LabeledError::new("Timestamp out of range").with_label(
+                "Nanosecond timestamps are limited to the years 1677-2262",
+                span,
+            )
+        })?,
+        other => {
+            return Err(LabeledError::new("Invalid precision").with_label(
+                format!(
+                    "Unknown precision '{}'. Valid precisions: ms, us, ns",
+                    other
+                ),
+                span,
+            ));
+        }
+    };
+
+    Ok(Value::int(epoch, span))
+}
+
 /// Parses a timestamp string or number into multiple date-time formats.
 pub struct UlidTimeParseCommand;
 
@@ -99,7 +183,7 @@ impl PluginCommand for UlidTimeParseCommand {
     }
 
     fn description(&self) -> &str {
-        "Parse a timestamp string or number into various formats"
+        "Parse a timestamp string, number, or date into various formats"
     }
 
     fn signature(&self) -> Signature {
@@ -107,7 +191,7 @@ impl PluginCommand for UlidTimeParseCommand {
             .required(
                 "timestamp",
                 SyntaxShape::Any,
-                "Timestamp to parse (string, int, or number)",
+                "Timestamp to parse: RFC 3339, RFC 2822, 'YYYY-MM-DD HH:MM:SS', 'YYYY-MM-DD', or 'YYYY/MM/DD' string, int, number, or date",
             )
             .input_output_types(vec![(Type::Nothing, Type::Record(vec![].into()))])
             .category(Category::Date)
@@ -120,6 +204,16 @@ impl PluginCommand for UlidTimeParseCommand {
                 description: "Parse an ISO8601 timestamp",
                 result: None,
             },
+            Example {
+                example: "ulid time parse '2024-01-01 12:00:00'",
+                description: "Parse a space-separated datetime, read as UTC",
+                result: None,
+            },
+            Example {
+                example: "ulid time parse 'Mon, 01 Jan 2024 00:00:00 +0000'",
+                description: "Parse an RFC 2822 timestamp",
+                result: None,
+            },
             Example {
                 example: "ulid time parse 1704067200000",
                 description: "Parse a millisecond timestamp",
@@ -130,6 +224,11 @@ impl PluginCommand for UlidTimeParseCommand {
                 description: "Parse a second timestamp",
                 result: None,
             },
+            Example {
+                example: "ulid time parse (date now)",
+                description: "Parse a Nushell date",
+                result: None,
+            },
         ]
     }
 
@@ -189,6 +288,11 @@ impl PluginCommand for UlidTimeMillisCommand {
                 description: "Convert seconds to milliseconds",
                 result: Some(Value::int(1704067200000, Span::test_data())),
             },
+            Example {
+                example: "ulid time millis (date now)",
+                description: "Convert a Nushell date to milliseconds",
+                result: None,
+            },
         ]
     }
 
@@ -200,45 +304,80 @@ impl PluginCommand for UlidTimeMillisCommand {
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let timestamp: Option<Value> = call.opt(0)?;
+        let millis = timestamp_to_millis(timestamp, call.head)?;
 
-        let millis = match timestamp {
-            None => Utc::now().timestamp_millis(),
-            Some(Value::String { val, .. }) => {
-                let datetime = DateTime::parse_from_rfc3339(&val)
-                    .or_else(|_| DateTime::parse_from_str(&val, "%Y-%m-%dT%H:%M:%S%.3fZ"))
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .map_err(|e| {
-                        LabeledError::new("Failed to parse timestamp")
-                            .with_label(format!("Invalid timestamp format: {}", e), call.head)
-                    })?;
-                datetime.timestamp_millis()
-            }
-            Some(Value::Int { val, .. }) => {
-                if val > TIMESTAMP_MILLIS_THRESHOLD {
-                    // Already milliseconds
-                    val
-                } else {
-                    // Seconds, convert to milliseconds
-                    val * crate::MS_PER_SECOND as i64
-                }
-            }
-            Some(Value::Float { val, .. }) => {
-                if val > TIMESTAMP_MILLIS_THRESHOLD as f64 {
-                    // Already milliseconds
-                    val as i64
-                } else {
-                    // Seconds, convert to milliseconds
-                    (val * crate::MS_PER_SECOND as f64) as i64
-                }
+        Ok(PipelineData::Value(Value::int(millis, call.head), None))
+    }
+}
+
+/// Converts a string, int, float, or date timestamp to Unix milliseconds.
+///
+/// Integers and floats at or below the millisecond threshold are read as seconds; `None`
+/// means now.
+pub(crate) fn timestamp_to_millis(
+    timestamp: Option<Value>,
+    span: nu_protocol::Span,
+) -> Result<i64, LabeledError> {
+    let millis = match timestamp {
+        None => Utc::now().timestamp_millis(),
+        Some(Value::String { val, .. }) => parse_datetime_str(&val, span)?.timestamp_millis(),
+        Some(Value::Int { val, .. }) => {
+            if val > TIMESTAMP_MILLIS_THRESHOLD {
+                // Already milliseconds
+                val
+            } else {
+                // Seconds, convert to milliseconds
+                val * crate::MS_PER_SECOND as i64
             }
-            Some(_) => {
-                return Err(LabeledError::new("Invalid input type")
-                    .with_label("Expected string, int, or float", call.head));
+        }
+        Some(Value::Float { val, .. }) => {
+            if val > TIMESTAMP_MILLIS_THRESHOLD as f64 {
+                // Already milliseconds
+                val as i64
+            } else {
+                // Seconds, convert to milliseconds
+                (val * crate::MS_PER_SECOND as f64) as i64
             }
-        };
+        }
+        Some(Value::Date { val, .. }) => val.timestamp_millis(),
+        Some(_) => {
+            return Err(LabeledError::new("Invalid input type")
+                .with_label("Expected string, int, float, or date", span));
+        }
+    };
 
-        Ok(PipelineData::Value(Value::int(millis, call.head), None))
+    Ok(millis)
+}
+
+/// Parses a timestamp string, trying each accepted format in turn.
+///
+/// The order is RFC 3339, RFC 2822, `YYYY-MM-DDTHH:MM:SS.sssZ`, `YYYY-MM-DD HH:MM:SS`,
+/// `YYYY-MM-DD`, and `YYYY/MM/DD`. Formats without an offset are read as UTC.
+fn parse_datetime_str(val: &str, span: Span) -> Result<DateTime<Utc>, LabeledError> {
+    let with_offset =
+        DateTime::parse_from_rfc3339(val).or_else(|_| DateTime::parse_from_rfc2822(val));
+    if let Ok(datetime) = with_offset {
+        return Ok(datetime.with_timezone(&Utc));
     }
+
+    FALLBACK_DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(val, format).ok())
+        .or_else(|| {
+            FALLBACK_DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(val, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|naive| naive.and_utc())
+        .ok_or_else(|| {
+            LabeledError::new("Failed to parse timestamp")
+                .with_label(format!("Invalid timestamp format: '{}'", val), span)
+                .with_help(
+                    "Accepted formats: RFC 3339, RFC 2822, 'YYYY-MM-DD HH:MM:SS', 'YYYY-MM-DD', \
+                     'YYYY/MM/DD', or a Unix timestamp in seconds or milliseconds",
+                )
+        })
 }
 
 fn parse_timestamp_to_datetime(
@@ -246,13 +385,7 @@ fn parse_timestamp_to_datetime(
     span: nu_protocol::Span,
 ) -> Result<DateTime<Utc>, LabeledError> {
     match timestamp {
-        Value::String { val, .. } => DateTime::parse_from_rfc3339(&val)
-            .or_else(|_| DateTime::parse_from_str(&val, "%Y-%m-%dT%H:%M:%S%.3fZ"))
-            .map(|dt| dt.with_timezone(&Utc))
-            .map_err(|e| {
-                LabeledError::new("Failed to parse timestamp")
-                    .with_label(format!("Invalid timestamp format: {}", e), span)
-            }),
+        Value::String { val, .. } => parse_datetime_str(&val, span),
         Value::Int { val, .. } => if val > TIMESTAMP_MILLIS_THRESHOLD {
             Utc.timestamp_millis_opt(val).single()
         } else {
@@ -268,8 +401,9 @@ fn parse_timestamp_to_datetime(
                 LabeledError::new("Invalid timestamp").with_label("Timestamp is out of range", span)
             })
         }
+        Value::Date { val, .. } => Ok(val.with_timezone(&Utc)),
         _ => Err(LabeledError::new("Invalid input type")
-            .with_label("Expected string, int, or float", span)),
+            .with_label("Expected string, int, float, or date", span)),
     }
 }
 
@@ -306,6 +440,43 @@ fn build_datetime_record(datetime: DateTime<Utc>, span: nu_protocol::Span) -> Va
     )
 }
 
+/// Formats a non-negative duration in its largest whole unit, e.g. "2 hours".
+///
+/// Months and years are approximate, based on the average Gregorian year. Spans of a year
+/// or more also show the remaining months, e.g. "2 years 3 months".
+pub(crate) fn humanize_duration(duration: chrono::Duration) -> String {
+    let total_seconds = duration.num_seconds();
+
+    if total_seconds < SECONDS_PER_MINUTE {
+        count_of(total_seconds, "second")
+    } else if total_seconds < SECONDS_PER_HOUR {
+        count_of(total_seconds / SECONDS_PER_MINUTE, "minute")
+    } else if total_seconds < SECONDS_PER_DAY {
+        count_of(total_seconds / SECONDS_PER_HOUR, "hour")
+    } else if total_seconds < SECONDS_PER_WEEK {
+        count_of(total_seconds / SECONDS_PER_DAY, "day")
+    } else if total_seconds < SECONDS_PER_MONTH {
+        count_of(total_seconds / SECONDS_PER_WEEK, "week")
+    } else if total_seconds < SECONDS_PER_YEAR {
+        count_of(total_seconds / SECONDS_PER_MONTH, "month")
+    } else {
+        let years = count_of(total_seconds / SECONDS_PER_YEAR, "year");
+        match (total_seconds % SECONDS_PER_YEAR) / SECONDS_PER_MONTH {
+            0 => years,
+            months => format!("{} {}", years, count_of(months, "month")),
+        }
+    }
+}
+
+/// Formats `count` with `unit`, pluralised unless the count is exactly one.
+fn count_of(count: i64, unit: &str) -> String {
+    if count == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +496,68 @@ mod tests {
 
             assert_eq!(signature.name, "ulid time now");
             assert!(signature.named.iter().any(|flag| flag.long == "format"));
+            assert!(signature.named.iter().any(|flag| flag.long == "precision"));
+        }
+
+        #[test]
+        fn test_precision_digits() {
+            let span = Span::test_data();
+            let datetime = Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap();
+
+            let ms = epoch_at_precision(datetime, "ms", span)
+                .unwrap()
+                .as_int()
+                .unwrap();
+            let us = epoch_at_precision(datetime, "us", span)
+                .unwrap()
+                .as_int()
+                .unwrap();
+            let ns = epoch_at_precision(datetime, "ns", span)
+                .unwrap()
+                .as_int()
+                .unwrap();
+
+            assert_eq!(ms, 1_700_000_000_123);
+            assert_eq!(us, 1_700_000_000_123_456);
+            assert_eq!(ns, 1_700_000_000_123_456_789);
+            assert!(ns.to_string().len() > ms.to_string().len());
+        }
+
+        #[test]
+        fn test_precision_conflicts_with_seconds_format() {
+            let now = Utc::now();
+            for precision in ["ms", "us", "ns"] {
+                let error = format_now(now, Some("seconds"), Some(precision), Span::test_data())
+                    .unwrap_err();
+                assert_eq!(error.msg, "Conflicting flags");
+            }
+        }
+
+        #[test]
+        fn test_precision_refines_millis_format() {
+            let now = Utc::now();
+            let span = Span::test_data();
+            assert_eq!(
+                format_now(now, Some("millis"), Some("us"), span).unwrap(),
+                Value::int(now.timestamp_micros(), span)
+            );
+            assert_eq!(
+                format_now(now, None, Some("ms"), span).unwrap(),
+                Value::int(now.timestamp_millis(), span)
+            );
+        }
+
+        #[test]
+        fn test_precision_with_string_format_errors() {
+            let error =
+                format_now(Utc::now(), Some("rfc3339"), Some("ns"), Span::test_data()).unwrap_err();
+            assert_eq!(error.msg, "Invalid precision");
+        }
+
+        #[test]
+        fn test_unknown_precision_errors() {
+            let datetime = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+            assert!(epoch_at_precision(datetime, "ps", Span::test_data()).is_err());
         }
 
         #[test]
@@ -1176,6 +1409,65 @@ mod tests {
         }
     }
 
+    mod humanize_duration_tests {
+        use super::*;
+
+        #[test]
+        fn test_units() {
+            assert_eq!(
+                humanize_duration(chrono::Duration::seconds(30)),
+                "30 seconds"
+            );
+            assert_eq!(
+                humanize_duration(chrono::Duration::seconds(120)),
+                "2 minutes"
+            );
+            assert_eq!(
+                humanize_duration(chrono::Duration::seconds(7200)),
+                "2 hours"
+            );
+            assert_eq!(
+                humanize_duration(chrono::Duration::seconds(172800)),
+                "2 days"
+            );
+        }
+
+        #[test]
+        fn test_weeks() {
+            assert_eq!(humanize_duration(chrono::Duration::days(14)), "2 weeks");
+            assert_eq!(humanize_duration(chrono::Duration::days(29)), "4 weeks");
+        }
+
+        #[test]
+        fn test_months() {
+            assert_eq!(humanize_duration(chrono::Duration::days(31)), "1 month");
+            assert_eq!(humanize_duration(chrono::Duration::days(100)), "3 months");
+        }
+
+        #[test]
+        fn test_single_units_are_singular() {
+            assert_eq!(humanize_duration(chrono::Duration::seconds(1)), "1 second");
+            assert_eq!(humanize_duration(chrono::Duration::seconds(60)), "1 minute");
+            assert_eq!(humanize_duration(chrono::Duration::hours(1)), "1 hour");
+            assert_eq!(humanize_duration(chrono::Duration::days(1)), "1 day");
+            assert_eq!(humanize_duration(chrono::Duration::days(7)), "1 week");
+            assert_eq!(humanize_duration(chrono::Duration::seconds(0)), "0 seconds");
+        }
+
+        #[test]
+        fn test_years() {
+            assert_eq!(humanize_duration(chrono::Duration::days(366)), "1 year");
+            assert_eq!(
+                humanize_duration(chrono::Duration::days(835)),
+                "2 years 3 months"
+            );
+            assert_eq!(
+                humanize_duration(chrono::Duration::days(400)),
+                "1 year 1 month"
+            );
+        }
+    }
+
     mod parse_timestamp_to_datetime_tests {
         use super::*;
 
@@ -1206,6 +1498,38 @@ mod tests {
             assert!(parse_timestamp_to_datetime(val, span).is_err());
         }
 
+        #[test]
+        fn test_space_separated_string_is_utc() {
+            let span = create_test_span();
+            let val = Value::string("2024-06-15 12:30:45", span);
+            let dt = parse_timestamp_to_datetime(val, span).unwrap();
+            assert_eq!(dt.timestamp(), 1_718_454_645);
+        }
+
+        #[test]
+        fn test_rfc2822_string() {
+            let span = create_test_span();
+            let val = Value::string("Sat, 15 Jun 2024 14:30:45 +0200", span);
+            let dt = parse_timestamp_to_datetime(val, span).unwrap();
+            assert_eq!(dt.timestamp(), 1_718_454_645);
+        }
+
+        #[test]
+        fn test_date_only_strings_are_midnight_utc() {
+            let span = create_test_span();
+            for input in ["2024-01-01", "2024/01/01"] {
+                let dt = parse_timestamp_to_datetime(Value::string(input, span), span).unwrap();
+                assert_eq!(dt.timestamp(), 1_704_067_200);
+            }
+        }
+
+        #[test]
+        fn test_unparseable_string_lists_accepted_formats() {
+            let span = create_test_span();
+            let error = parse_datetime_str("15.06.2024", span).unwrap_err();
+            assert!(error.help.unwrap().contains("RFC 2822"));
+        }
+
         #[test]
         fn test_int_seconds() {
             let span = create_test_span();
@@ -1235,6 +1559,27 @@ mod tests {
             assert_eq!(dt.nanosecond(), 500_000_000);
         }
 
+        #[test]
+        fn test_native_date() {
+            let span = create_test_span();
+            let date = DateTime::parse_from_rfc3339("2024-01-01T02:00:00.250+02:00").unwrap();
+            let dt = parse_timestamp_to_datetime(Value::date(date, span), span).unwrap();
+            assert_eq!(dt.timestamp_millis(), 1704067200250);
+            assert_eq!(dt.hour(), 0);
+
+            let record = build_datetime_record(dt, span);
+            assert_eq!(
+                record
+                    .as_record()
+                    .unwrap()
+                    .get("iso8601")
+                    .unwrap()
+                    .as_str()
+                    .unwrap(),
+                "2024-01-01T00:00:00.250Z"
+            );
+        }
+
         #[test]
         fn test_invalid_type_returns_error() {
             let span = create_test_span();
@@ -1243,6 +1588,39 @@ mod tests {
         }
     }
 
+    mod timestamp_to_millis_tests {
+        use super::*;
+
+        #[test]
+        fn test_native_date() {
+            let span = create_test_span();
+            let date = DateTime::parse_from_rfc3339("2024-01-01T00:00:00.123Z").unwrap();
+            assert_eq!(
+                timestamp_to_millis(Some(Value::date(date, span)), span).unwrap(),
+                1704067200123
+            );
+        }
+
+        #[test]
+        fn test_seconds_and_millis() {
+            let span = create_test_span();
+            assert_eq!(
+                timestamp_to_millis(Some(Value::int(1704067200, span)), span).unwrap(),
+                1704067200000
+            );
+            assert_eq!(
+                timestamp_to_millis(Some(Value::int(1704067200000, span)), span).unwrap(),
+                1704067200000
+            );
+        }
+
+        #[test]
+        fn test_invalid_type_returns_error() {
+            let span = create_test_span();
+            assert!(timestamp_to_millis(Some(Value::bool(true, span)), span).is_err());
+        }
+    }
+
     mod build_datetime_record_tests {
         use super::*;
 
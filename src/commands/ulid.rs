@@ -1,11 +1,18 @@
 //! Core ULID commands for generation, validation, parsing, and security advice.
 
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
-    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
 };
 
-use crate::{SecurityWarnings, UlidEngine, UlidPlugin};
+use ulid::Generator;
+
+use crate::{ClockMonitor, SecurityWarnings, UlidComponents, UlidEngine, UlidError, UlidPlugin};
 
 /// Generates new ULIDs with optional count and timestamp.
 pub struct UlidGenerateCommand;
@@ -26,7 +33,7 @@ impl PluginCommand for UlidGenerateCommand {
             .named(
                 "count",
                 SyntaxShape::Int,
-                "Number of ULIDs to generate (max 10,000)",
+                "Number of ULIDs to generate as a list, even for 0 or 1 (max 10,000)",
                 Some('c'),
             )
             .named(
@@ -35,6 +42,28 @@ impl PluginCommand for UlidGenerateCommand {
                 "Custom timestamp in milliseconds",
                 Some('t'),
             )
+            .named(
+                "increment",
+                SyntaxShape::Int,
+                "Milliseconds to advance --timestamp by for each ULID in a --count batch",
+                Some('i'),
+            )
+            .named(
+                "append",
+                SyntaxShape::Filepath,
+                "Also append the generated ULIDs to a file, one per line",
+                Some('a'),
+            )
+            .switch(
+                "sortable",
+                "Use the plugin's monotonic generator so ULIDs sort in generation order; running out of randomness within one millisecond is an error",
+                Some('s'),
+            )
+            .switch(
+                "strict-clock",
+                "Fail instead of warning when the system clock moved backward since the last generation",
+                None,
+            )
             .input_output_types(vec![
                 (Type::Nothing, Type::String),
                 (Type::Nothing, Type::List(Box::new(Type::String))),
@@ -59,23 +88,90 @@ impl PluginCommand for UlidGenerateCommand {
                 description: "Generate a ULID with specific timestamp",
                 result: None,
             },
+            Example {
+                example: "ulid generate --count 5 --timestamp 1704067200000 --increment 1000",
+                description: "Generate five ULIDs spaced one second apart from a fixed start",
+                result: None,
+            },
+            Example {
+                example: "ulid generate --count 5 --sortable",
+                description: "Generate 5 ULIDs that are strictly increasing",
+                result: None,
+            },
+            Example {
+                example: "ulid generate --append ids.txt",
+                description: "Generate a ULID and record it in a newline-delimited registry file",
+                result: None,
+            },
+            Example {
+                example: "ulid generate --count 100 --strict-clock",
+                description: "Generate ULIDs, failing if the system clock stepped backward",
+                result: None,
+            },
         ]
     }
 
     fn run(
         &self,
-        _plugin: &Self::Plugin,
-        _engine: &EngineInterface,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let count: Option<i64> = call.get_flag("count")?;
         let timestamp: Option<i64> = call.get_flag("timestamp")?;
+        let increment: Option<i64> = call.get_flag("increment")?;
+        let append: Option<String> = call.get_flag("append")?;
+        let sortable = call.has_flag("sortable")?;
+        let strict_clock = call.has_flag("strict-clock")?;
+
+        let timestamps = match (increment, timestamp, count) {
+            (Some(i), _, _) if i < 0 => {
+                return Err(LabeledError::new("Invalid increment")
+                    .with_label("Increment must not be negative", call.head));
+            }
+            (Some(_), None, _) | (Some(_), _, None) => {
+                return Err(LabeledError::new("Missing flags")
+                    .with_label("--increment requires --timestamp and --count", call.head));
+            }
+            (increment, Some(start), _) => {
+                Some(BatchTimestamps::new(start, increment.unwrap_or(0)))
+            }
+            (None, None, _) => None,
+        };
+
+        let mut generator = if sortable {
+            Some(plugin.generator.lock().map_err(|_| {
+                LabeledError::new("Generation failed")
+                    .with_label("Monotonic generator is unavailable", call.head)
+            })?)
+        } else {
+            None
+        };
+        let generator = generator.as_deref_mut();
+
+        let result = match count {
+            Some(c) => generate_bulk_ulids(
+                c,
+                timestamps,
+                generator,
+                &plugin.clock,
+                strict_clock,
+                call.head,
+            ),
+            None => {
+                generate_single_ulid(timestamp, generator, &plugin.clock, strict_clock, call.head)
+            }
+        }?;
 
-        match count {
-            Some(c) => generate_bulk_ulids(c, timestamp, call.head),
-            None => generate_single_ulid(timestamp, call.head),
+        if let Some(path) = append {
+            let path = PathBuf::from(engine.get_current_dir()?).join(path);
+            if let PipelineData::Value(value, _) = &result {
+                append_ulids(&path, value, call.head)?;
+            }
         }
+
+        Ok(result)
     }
 }
 
@@ -90,12 +186,22 @@ impl PluginCommand for UlidValidateCommand {
     }
 
     fn description(&self) -> &str {
-        "Validate if a string is a valid ULID"
+        "Validate if a string or binary value is a valid ULID"
     }
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .required("ulid", SyntaxShape::String, "The ULID string to validate")
+            .required(
+                "ulid",
+                SyntaxShape::Any,
+                "The ULID string or 16-byte binary to validate",
+            )
+            .named(
+                "prefix",
+                SyntaxShape::String,
+                "Type prefix to strip before validating, e.g. 'user_'",
+                Some('p'),
+            )
             .input_output_types(vec![(Type::Nothing, Type::Bool)])
             .category(Category::Strings)
     }
@@ -122,12 +228,67 @@ impl PluginCommand for UlidValidateCommand {
         call: &EvaluatedCall,
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
-        let ulid_str: String = call.req(0)?;
-        let is_valid = UlidEngine::validate(&ulid_str);
+        let ulid: Value = call.req(0)?;
+        let prefix: Option<String> = call.get_flag("prefix")?;
+        let is_valid = match ulid {
+            Value::String { val, .. } => validate_with_prefix(&val, prefix.as_deref()),
+            Value::Binary { val, .. } => UlidEngine::validate_bytes(&val),
+            other => return Err(invalid_validate_input(&other, call.head)),
+        };
         Ok(PipelineData::Value(Value::bool(is_valid, call.head), None))
     }
 }
 
+/// Summarises ULID validity across a list in a single pass.
+pub struct UlidValidateSummaryCommand;
+
+impl PluginCommand for UlidValidateSummaryCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid validate summary"
+    }
+
+    fn description(&self) -> &str {
+        "Count valid and invalid ULIDs in a list and report where the invalid ones are"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::Record(vec![].into()),
+            )])
+            .category(Category::Strings)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"["01AN4Z07BY79KA1307SR9X4MV3", "invalid"] | ulid validate summary"#,
+                description: "Summarise the validity of a list of ULIDs",
+                result: None,
+            },
+            Example {
+                example: "open events.csv | get id | ulid validate summary | get invalid_indices",
+                description: "Find the rows with malformed IDs",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let summary = summarise_validation(input.into_value(call.head)?, call.head)?;
+        Ok(PipelineData::Value(summary, None))
+    }
+}
+
 /// Parses a ULID string and extracts its timestamp and randomness components.
 pub struct UlidParseCommand;
 
@@ -144,17 +305,83 @@ impl PluginCommand for UlidParseCommand {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .required("ulid", SyntaxShape::String, "The ULID string to parse")
-            .input_output_types(vec![(Type::Nothing, Type::Record(vec![].into()))])
+            .required(
+                "ulid",
+                SyntaxShape::Any,
+                "The ULID string, or list of ULID strings, to parse",
+            )
+            .named(
+                "prefix",
+                SyntaxShape::String,
+                "Type prefix to strip before parsing, e.g. 'user_'",
+                Some('p'),
+            )
+            .switch(
+                "warn-implausible",
+                "Add a timestamp_warning field when the timestamp year is outside the plausible window",
+                None,
+            )
+            .named(
+                "min-year",
+                SyntaxShape::Int,
+                "Earliest plausible year for --warn-implausible (default: 2015)",
+                None,
+            )
+            .named(
+                "max-year",
+                SyntaxShape::Int,
+                "Latest plausible year for --warn-implausible (default: 2100)",
+                None,
+            )
+            .named(
+                "on-invalid",
+                SyntaxShape::String,
+                "When parsing a list, handle invalid entries with 'null', 'skip', or 'error' (default: error); rejected for a single ULID",
+                None,
+            )
+            .switch(
+                "include-bytes",
+                "Add a bytes field with the ULID's 16-byte binary form",
+                Some('b'),
+            )
+            .input_output_types(vec![
+                (Type::Nothing, Type::Record(vec![].into())),
+                (
+                    Type::Nothing,
+                    Type::List(Box::new(Type::Record(vec![].into()))),
+                ),
+            ])
             .category(Category::Strings)
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
-        vec![Example {
-            example: "ulid parse '01AN4Z07BY79KA1307SR9X4MV3'",
-            description: "Parse a ULID and show its components",
-            result: None,
-        }]
+        vec![
+            Example {
+                example: "ulid parse '01AN4Z07BY79KA1307SR9X4MV3'",
+                description: "Parse a ULID and show its components",
+                result: None,
+            },
+            Example {
+                example: "ulid parse 'user_01AN4Z07BY79KA1307SR9X4MV3' --prefix user_",
+                description: "Parse a prefixed ID",
+                result: None,
+            },
+            Example {
+                example: "ulid parse (open events.csv | get id) --on-invalid null",
+                description: "Parse a column of ULIDs, keeping a null in place of each invalid entry",
+                result: None,
+            },
+            Example {
+                example: "ulid parse $id --warn-implausible --max-year 2030",
+                description: "Flag a ULID whose timestamp is after 2030",
+                result: None,
+            },
+            Example {
+                example: "ulid parse '01AN4Z07BY79KA1307SR9X4MV3' --include-bytes | get bytes",
+                description: "Parse a ULID and get its binary form for storage",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -164,15 +391,36 @@ impl PluginCommand for UlidParseCommand {
         call: &EvaluatedCall,
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
-        let ulid_str: String = call.req(0)?;
-
-        match UlidEngine::parse(&ulid_str) {
-            Ok(components) => {
-                let value = UlidEngine::components_to_value(&components, call.head);
-                Ok(PipelineData::Value(value, None))
+        let ulid: Value = call.req(0)?;
+        let prefix: Option<String> = call.get_flag("prefix")?;
+        let on_invalid: Option<String> = call.get_flag("on-invalid")?;
+        let plausible_years = plausible_year_range(call)?;
+        let include_bytes = call.has_flag("include-bytes")?;
+        let on_invalid = on_invalid_mode(on_invalid.as_deref(), &ulid, call.head)?;
+
+        let value = match ulid {
+            Value::String { val, .. } => {
+                let components = parse_with_prefix(&val, prefix.as_deref())
+                    .map_err(|e| parse_failed(Some(&val), e.to_string(), call.head))?;
+                build_parse_output(&components, plausible_years, include_bytes, call.head)?
             }
-            Err(e) => Err(LabeledError::new("Parse failed").with_label(e.to_string(), call.head)),
-        }
+            Value::List { vals, .. } => parse_batch(
+                &vals,
+                on_invalid,
+                prefix.as_deref(),
+                plausible_years,
+                include_bytes,
+                call.head,
+            )?,
+            _ => {
+                return Err(LabeledError::new("Invalid input type").with_label(
+                    "Expected a ULID string or a list of ULID strings",
+                    call.head,
+                ));
+            }
+        };
+
+        Ok(PipelineData::Value(value, None))
     }
 }
 
@@ -216,25 +464,68 @@ impl PluginCommand for UlidSecurityAdviceCommand {
     }
 }
 
+/// Generates one ULID as a bare string; used when `--count` is omitted.
 fn generate_single_ulid(
     timestamp: Option<i64>,
+    generator: Option<&mut Generator>,
+    clock: &ClockMonitor,
+    strict_clock: bool,
     span: nu_protocol::Span,
 ) -> Result<PipelineData, LabeledError> {
-    let ulid = match timestamp {
-        Some(ts) => UlidEngine::generate_with_timestamp(ts as u64),
-        None => UlidEngine::generate(),
+    let ulid = match (timestamp, generator) {
+        (ts, Some(generator)) => UlidEngine::generate_monotonic(generator, ts.map(|ts| ts as u64)),
+        (Some(ts), None) => UlidEngine::generate_with_timestamp(ts as u64),
+        (None, None) => UlidEngine::generate(),
     }
     .map_err(|e| LabeledError::new("Generation failed").with_label(e.to_string(), span))?;
 
+    if timestamp.is_none() {
+        check_clock(clock, ulid.timestamp_ms(), strict_clock, span)?;
+    }
+
     Ok(PipelineData::Value(
         Value::string(ulid.to_string(), span),
         None,
     ))
 }
 
+/// Timestamps for a `--timestamp` batch, advancing by `--increment` milliseconds per ULID.
+#[derive(Clone, Copy)]
+struct BatchTimestamps {
+    start: u64,
+    increment: i64,
+}
+
+impl BatchTimestamps {
+    fn new(start: i64, increment: i64) -> Self {
+        Self {
+            start: start as u64,
+            increment,
+        }
+    }
+
+    /// Returns the timestamp of the ULID at `index` in the batch.
+    fn at(&self, index: usize) -> Result<u64, UlidError> {
+        let offset =
+            (index as i64)
+                .checked_mul(self.increment)
+                .ok_or_else(|| UlidError::InvalidInput {
+                    message: format!(
+                        "Increment of {} ms overflows at item {}",
+                        self.increment, index
+                    ),
+                })?;
+        UlidEngine::offset_timestamp(self.start, offset)
+    }
+}
+
+/// Generates `count` ULIDs as a list; a count of 0 yields an empty list, never a bare value.
 fn generate_bulk_ulids(
     count: i64,
-    timestamp: Option<i64>,
+    timestamps: Option<BatchTimestamps>,
+    generator: Option<&mut Generator>,
+    clock: &ClockMonitor,
+    strict_clock: bool,
     span: nu_protocol::Span,
 ) -> Result<PipelineData, LabeledError> {
     let count_usize = if count < 0 {
@@ -248,20 +539,42 @@ fn generate_bulk_ulids(
         count as usize
     };
 
-    let ulids = match timestamp {
-        Some(ts) => {
-            let mut result = Vec::new();
-            for _ in 0..count_usize {
-                let ulid = UlidEngine::generate_with_timestamp(ts as u64).map_err(|e| {
-                    LabeledError::new("Generation failed").with_label(e.to_string(), span)
+    let ulids = match (timestamps, generator) {
+        (ts, Some(generator)) => {
+            let ulids = (0..count_usize)
+                .map(|index| {
+                    let ts = ts.map(|ts| ts.at(index)).transpose()?;
+                    UlidEngine::generate_monotonic(generator, ts)
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    LabeledError::new("Bulk generation failed").with_label(e.to_string(), span)
                 })?;
+            if ts.is_none() {
+                check_batch_clock(clock, &ulids, strict_clock, span)?;
+            }
+            ulids
+        }
+        (Some(ts), None) => {
+            let mut result = Vec::new();
+            for index in 0..count_usize {
+                let ulid = ts
+                    .at(index)
+                    .and_then(UlidEngine::generate_with_timestamp)
+                    .map_err(|e| {
+                        LabeledError::new("Generation failed").with_label(e.to_string(), span)
+                    })?;
                 result.push(ulid);
             }
             result
         }
-        None => UlidEngine::generate_bulk(count_usize).map_err(|e| {
-            LabeledError::new("Bulk generation failed").with_label(e.to_string(), span)
-        })?,
+        (None, None) => {
+            let ulids = UlidEngine::generate_bulk(count_usize).map_err(|e| {
+                LabeledError::new("Bulk generation failed").with_label(e.to_string(), span)
+            })?;
+            check_batch_clock(clock, &ulids, strict_clock, span)?;
+            ulids
+        }
     };
 
     let values: Vec<Value> = ulids
@@ -272,6 +585,299 @@ fn generate_bulk_ulids(
     Ok(PipelineData::Value(Value::list(values, span), None))
 }
 
+/// Summarises a list for `ulid validate summary`, hinting at UUIDs when given a single one.
+fn summarise_validation(value: Value, span: Span) -> Result<Value, LabeledError> {
+    match value {
+        Value::List { vals, .. } => Ok(build_validation_summary(&vals, span)),
+        other => Err(with_uuid_hint(
+            LabeledError::new("Invalid input").with_label("Expected a list of ULIDs", span),
+            other.as_str().ok(),
+        )),
+    }
+}
+
+fn build_validation_summary(vals: &[Value], span: Span) -> Value {
+    let invalid_indices: Vec<Value> = vals
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !is_valid_ulid_value(value))
+        .map(|(index, _)| Value::int(index as i64, span))
+        .collect();
+
+    let mut record = Record::new();
+    record.push("total", Value::int(vals.len() as i64, span));
+    record.push(
+        "valid",
+        Value::int((vals.len() - invalid_indices.len()) as i64, span),
+    );
+    record.push("invalid", Value::int(invalid_indices.len() as i64, span));
+    record.push("invalid_indices", Value::list(invalid_indices, span));
+    Value::record(record, span)
+}
+
+/// Returns whether `value` is a valid ULID string or 16-byte binary; any other type is invalid.
+pub(crate) fn is_valid_ulid_value(value: &Value) -> bool {
+    match value {
+        Value::String { val, .. } => UlidEngine::validate(val),
+        Value::Binary { val, .. } => UlidEngine::validate_bytes(val),
+        _ => false,
+    }
+}
+
+/// Appends generated ULIDs to `path` as newline-delimited entries, creating the file if needed.
+fn append_ulids(path: &Path, value: &Value, span: Span) -> Result<(), LabeledError> {
+    let ulids: Vec<&str> = match value {
+        Value::List { vals, .. } => vals.iter().filter_map(|v| v.as_str().ok()).collect(),
+        other => other.as_str().into_iter().collect(),
+    };
+
+    let write_error = |e: std::io::Error| {
+        LabeledError::new("Append failed")
+            .with_label(format!("Cannot write to '{}': {}", path.display(), e), span)
+    };
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(write_error)?;
+    let mut writer = BufWriter::new(file);
+    for ulid in ulids {
+        writeln!(writer, "{}", ulid).map_err(write_error)?;
+    }
+    writer.flush().map_err(write_error)
+}
+
+/// Reports wall-clock regressions between generations.
+///
+/// Custom `--timestamp` values are deliberate and are not passed through here.
+fn check_clock(
+    clock: &ClockMonitor,
+    timestamp_ms: u64,
+    strict: bool,
+    span: Span,
+) -> Result<(), LabeledError> {
+    let Some(previous) = clock.observe(timestamp_ms) else {
+        return Ok(());
+    };
+
+    let message = format!(
+        "System clock moved backward by {} ms since a previous ULID was generated",
+        previous - timestamp_ms
+    );
+    if strict {
+        return Err(LabeledError::new("Clock moved backward").with_label(message, span));
+    }
+
+    eprintln!("Warning: {}", message);
+    Ok(())
+}
+
+/// Reports a wall-clock regression for a generated batch at most once.
+///
+/// Only the first ULID is checked against earlier generations, and the last one then advances
+/// the monitor, so a single clock step warns once instead of once per ULID.
+fn check_batch_clock(
+    clock: &ClockMonitor,
+    ulids: &[ulid::Ulid],
+    strict: bool,
+    span: Span,
+) -> Result<(), LabeledError> {
+    let (Some(first), Some(last)) = (ulids.first(), ulids.last()) else {
+        return Ok(());
+    };
+    check_clock(clock, first.timestamp_ms(), strict, span)?;
+    // Steps within one batch are not reported, so the regression this returns is not needed
+    clock.observe(last.timestamp_ms());
+    Ok(())
+}
+
+/// Builds a "Parse failed" error, with a hint when the input is a UUID rather than a ULID.
+fn parse_failed(input: Option<&str>, message: String, span: Span) -> LabeledError {
+    with_uuid_hint(
+        LabeledError::new("Parse failed").with_label(message, span),
+        input,
+    )
+}
+
+/// Builds the error for a value `ulid validate` cannot check.
+///
+/// `ulid validate` answers a UUID string with `false`, so the hint is given here instead, when
+/// a list passed by mistake holds UUIDs.
+fn invalid_validate_input(value: &Value, span: Span) -> LabeledError {
+    let uuid = match value {
+        Value::List { vals, .. } => vals
+            .iter()
+            .filter_map(|v| v.as_str().ok())
+            .find(|v| UlidEngine::looks_like_uuid(v)),
+        _ => None,
+    };
+    with_uuid_hint(
+        LabeledError::new("Invalid input type").with_label("Expected string or binary data", span),
+        uuid,
+    )
+}
+
+/// Adds help on converting a UUID when `input` is a UUID rather than a ULID.
+fn with_uuid_hint(error: LabeledError, input: Option<&str>) -> LabeledError {
+    match input {
+        Some(input) if UlidEngine::looks_like_uuid(input) => error.with_help(
+            "This looks like a UUID, not a ULID. To reinterpret its 128 bits as a ULID, use \
+             `ulid decode hex ($id | str replace --all '-' '') --as-ulid`",
+        ),
+        _ => error,
+    }
+}
+
+/// Validates `id` after stripping `prefix`; an ID without the prefix is invalid.
+fn validate_with_prefix(id: &str, prefix: Option<&str>) -> bool {
+    match prefix {
+        Some(prefix) => UlidEngine::strip_prefix(id, prefix).is_ok_and(UlidEngine::validate),
+        None => UlidEngine::validate(id),
+    }
+}
+
+fn parse_with_prefix(id: &str, prefix: Option<&str>) -> Result<UlidComponents, UlidError> {
+    match prefix {
+        Some(prefix) => UlidEngine::parse(UlidEngine::strip_prefix(id, prefix)?),
+        None => UlidEngine::parse(id),
+    }
+}
+
+/// Reads `--warn-implausible`, `--min-year`, and `--max-year` into an inclusive year window.
+///
+/// Returns `None` when `--warn-implausible` is not set.
+pub(crate) fn plausible_year_range(
+    call: &EvaluatedCall,
+) -> Result<Option<(i32, i32)>, LabeledError> {
+    if !call.has_flag("warn-implausible")? {
+        return Ok(None);
+    }
+
+    let min_year: Option<i64> = call.get_flag("min-year")?;
+    let max_year: Option<i64> = call.get_flag("max-year")?;
+    let min_year = min_year.map_or(crate::DEFAULT_MIN_PLAUSIBLE_YEAR, |y| y as i32);
+    let max_year = max_year.map_or(crate::DEFAULT_MAX_PLAUSIBLE_YEAR, |y| y as i32);
+
+    if min_year > max_year {
+        return Err(LabeledError::new("Invalid year range").with_label(
+            format!("--min-year {} is after --max-year {}", min_year, max_year),
+            call.head,
+        ));
+    }
+
+    Ok(Some((min_year, max_year)))
+}
+
+fn build_parse_output(
+    components: &UlidComponents,
+    plausible_years: Option<(i32, i32)>,
+    include_bytes: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let value = UlidEngine::components_to_value(components, span);
+    let value = if include_bytes {
+        with_bytes_field(value, &components.ulid, span)?
+    } else {
+        value
+    };
+
+    let warning = plausible_years.and_then(|(min_year, max_year)| {
+        UlidEngine::timestamp_warning(components.timestamp_ms, min_year, max_year)
+    });
+    match (warning, value) {
+        (Some(warning), Value::Record { val, .. }) => {
+            let mut record = val.into_owned();
+            record.push("timestamp_warning", Value::string(warning, span));
+            Ok(Value::record(record, span))
+        }
+        (_, value) => Ok(value),
+    }
+}
+
+/// Appends the ULID's 16-byte binary form to the parse output as a `bytes` field.
+fn with_bytes_field(value: Value, ulid_str: &str, span: Span) -> Result<Value, LabeledError> {
+    let Value::Record { val, .. } = value else {
+        return Ok(value);
+    };
+    let ulid = ulid_str
+        .parse::<ulid::Ulid>()
+        .map_err(|e| LabeledError::new("Parse failed").with_label(e.to_string(), span))?;
+
+    let mut record = val.into_owned();
+    record.push("bytes", Value::binary(UlidEngine::to_bytes(&ulid), span));
+    Ok(Value::record(record, span))
+}
+
+/// Checks `--on-invalid` before any input is parsed and returns the mode, defaulting to
+/// `error`.
+///
+/// The mode only applies to a list, so giving it with a single ULID is an error rather than
+/// being silently ignored.
+fn on_invalid_mode<'a>(
+    mode: Option<&'a str>,
+    input: &Value,
+    span: Span,
+) -> Result<&'a str, LabeledError> {
+    let Some(mode) = mode else {
+        return Ok("error");
+    };
+
+    if !matches!(mode, "null" | "skip" | "error") {
+        return Err(LabeledError::new("Invalid mode").with_label(
+            format!(
+                "Unknown --on-invalid mode '{}'. Valid modes: null, skip, error",
+                mode
+            ),
+            span,
+        ));
+    }
+    if !matches!(input, Value::List { .. }) {
+        return Err(LabeledError::new("Invalid flag").with_label(
+            "--on-invalid only applies when parsing a list of ULIDs",
+            span,
+        ));
+    }
+    Ok(mode)
+}
+
+/// Parses each entry of a list, handling invalid entries according to `on_invalid`, which
+/// must already have been checked by [`on_invalid_mode`].
+fn parse_batch(
+    vals: &[Value],
+    on_invalid: &str,
+    prefix: Option<&str>,
+    plausible_years: Option<(i32, i32)>,
+    include_bytes: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let mut parsed = Vec::with_capacity(vals.len());
+    for (index, value) in vals.iter().enumerate() {
+        let components = value
+            .as_str()
+            .map_err(|_| format!("Item {} is not a string", index))
+            .and_then(|ulid_str| {
+                parse_with_prefix(ulid_str, prefix).map_err(|e| format!("Item {}: {}", index, e))
+            });
+
+        match (components, on_invalid) {
+            (Ok(components), _) => parsed.push(build_parse_output(
+                &components,
+                plausible_years,
+                include_bytes,
+                span,
+            )?),
+            (Err(_), "null") => parsed.push(Value::nothing(span)),
+            (Err(_), "skip") => {}
+            (Err(message), _) => {
+                return Err(parse_failed(value.as_str().ok(), message, span));
+            }
+        }
+    }
+
+    Ok(Value::list(parsed, span))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,6 +898,12 @@ mod tests {
             assert_eq!(signature.name, "ulid generate");
             assert!(signature.named.iter().any(|flag| flag.long == "count"));
             assert!(signature.named.iter().any(|flag| flag.long == "timestamp"));
+            assert!(
+                signature
+                    .named
+                    .iter()
+                    .any(|flag| flag.long == "strict-clock")
+            );
             // Verify no --format flag exists (removed in favour of pipeline commands)
             assert!(
                 !signature.named.iter().any(|flag| flag.long == "format"),
@@ -437,6 +1049,66 @@ mod tests {
         }
     }
 
+    mod ulid_validate_summary_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let signature = UlidValidateSummaryCommand.signature();
+            assert_eq!(signature.name, "ulid validate summary");
+            assert!(signature.required_positional.is_empty());
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidValidateSummaryCommand.examples().is_empty());
+        }
+
+        #[test]
+        fn test_summary_counts_and_indices() {
+            let span = create_test_span();
+            let vals = vec![
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", span),
+                Value::string("invalid", span),
+                Value::string("01BX5ZZKBKACTAV9WEVGEMMVRY", span),
+                Value::binary(vec![0u8; 16], span),
+                Value::int(42, span),
+                Value::string("01AN4Z07BY79KA1307SR9X4MV", span),
+            ];
+
+            let summary = build_validation_summary(&vals, span);
+            let record = summary.as_record().unwrap();
+            assert_eq!(record.get("total").unwrap().as_int().unwrap(), 6);
+            assert_eq!(record.get("valid").unwrap().as_int().unwrap(), 3);
+            assert_eq!(record.get("invalid").unwrap().as_int().unwrap(), 3);
+
+            let indices: Vec<i64> = record
+                .get("invalid_indices")
+                .unwrap()
+                .as_list()
+                .unwrap()
+                .iter()
+                .map(|v| v.as_int().unwrap())
+                .collect();
+            assert_eq!(indices, vec![1, 4, 5]);
+        }
+
+        #[test]
+        fn test_empty_list() {
+            let summary = build_validation_summary(&[], create_test_span());
+            let record = summary.as_record().unwrap();
+            assert_eq!(record.get("total").unwrap().as_int().unwrap(), 0);
+            assert!(
+                record
+                    .get("invalid_indices")
+                    .unwrap()
+                    .as_list()
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+    }
+
     mod ulid_parse_command {
         use super::*;
 
@@ -448,6 +1120,14 @@ mod tests {
             assert_eq!(signature.name, "ulid parse");
             assert_eq!(signature.required_positional.len(), 1);
             assert_eq!(signature.required_positional[0].name, "ulid");
+            assert!(signature.named.iter().any(|flag| flag.long == "on-invalid"));
+            assert!(signature.named.iter().any(|flag| flag.long == "prefix"));
+            assert!(
+                signature
+                    .named
+                    .iter()
+                    .any(|flag| flag.long == "warn-implausible")
+            );
         }
 
         #[test]
@@ -929,7 +1609,8 @@ mod tests {
         #[test]
         fn test_generates_without_timestamp() {
             let span = create_test_span();
-            let result = generate_single_ulid(None, span).unwrap();
+            let result =
+                generate_single_ulid(None, None, &ClockMonitor::default(), false, span).unwrap();
             match result {
                 PipelineData::Value(Value::String { val, .. }, _) => {
                     assert_eq!(val.len(), crate::ULID_STRING_LENGTH);
@@ -941,7 +1622,14 @@ mod tests {
         #[test]
         fn test_generates_with_timestamp() {
             let span = create_test_span();
-            let result = generate_single_ulid(Some(1704067200000), span).unwrap();
+            let result = generate_single_ulid(
+                Some(1704067200000),
+                None,
+                &ClockMonitor::default(),
+                false,
+                span,
+            )
+            .unwrap();
             match result {
                 PipelineData::Value(Value::String { val, .. }, _) => {
                     assert_eq!(val.len(), crate::ULID_STRING_LENGTH);
@@ -957,7 +1645,8 @@ mod tests {
         #[test]
         fn test_generates_correct_count() {
             let span = create_test_span();
-            let result = generate_bulk_ulids(5, None, span).unwrap();
+            let result =
+                generate_bulk_ulids(5, None, None, &ClockMonitor::default(), false, span).unwrap();
             match result {
                 PipelineData::Value(Value::List { vals, .. }, _) => {
                     assert_eq!(vals.len(), 5);
@@ -969,19 +1658,32 @@ mod tests {
         #[test]
         fn test_negative_count_errors() {
             let span = create_test_span();
-            assert!(generate_bulk_ulids(-1, None, span).is_err());
+            assert!(
+                generate_bulk_ulids(-1, None, None, &ClockMonitor::default(), false, span).is_err()
+            );
         }
 
         #[test]
         fn test_over_max_count_errors() {
             let span = create_test_span();
-            assert!(generate_bulk_ulids(10_001, None, span).is_err());
+            assert!(
+                generate_bulk_ulids(10_001, None, None, &ClockMonitor::default(), false, span)
+                    .is_err()
+            );
         }
 
         #[test]
         fn test_with_timestamp() {
             let span = create_test_span();
-            let result = generate_bulk_ulids(3, Some(1704067200000), span).unwrap();
+            let result = generate_bulk_ulids(
+                3,
+                Some(BatchTimestamps::new(1704067200000, 0)),
+                None,
+                &ClockMonitor::default(),
+                false,
+                span,
+            )
+            .unwrap();
             match result {
                 PipelineData::Value(Value::List { vals, .. }, _) => {
                     assert_eq!(vals.len(), 3);
@@ -990,4 +1692,659 @@ mod tests {
             }
         }
     }
+
+    mod check_clock_tests {
+        use super::*;
+
+        const FAR_FUTURE_MS: u64 = 4_102_444_800_000; // 2100-01-01
+
+        #[test]
+        fn test_forward_clock_passes() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            assert!(check_clock(&clock, 1_000, true, span).is_ok());
+            assert!(check_clock(&clock, 2_000, true, span).is_ok());
+        }
+
+        #[test]
+        fn test_backward_clock_warns_by_default() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            check_clock(&clock, 2_000, false, span).unwrap();
+            assert!(check_clock(&clock, 1_000, false, span).is_ok());
+        }
+
+        #[test]
+        fn test_backward_clock_errors_when_strict() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            check_clock(&clock, 2_000, true, span).unwrap();
+            assert!(check_clock(&clock, 1_000, true, span).is_err());
+        }
+
+        #[test]
+        fn test_generation_after_clock_regression() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            // Simulate a previous generation stamped in the future
+            check_clock(&clock, FAR_FUTURE_MS, true, span).unwrap();
+
+            assert!(generate_single_ulid(None, None, &clock, true, span).is_err());
+            assert!(generate_bulk_ulids(3, None, None, &clock, true, span).is_err());
+            assert!(generate_single_ulid(None, None, &clock, false, span).is_ok());
+        }
+
+        #[test]
+        fn test_batch_is_checked_once_and_advances_the_monitor() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            let batch: Vec<ulid::Ulid> = [3_000, 1_000, 2_000, 4_000]
+                .into_iter()
+                .map(|ts| ulid::Ulid::from_parts(ts, 0))
+                .collect();
+
+            // Only the first ULID is compared, so the step back inside the batch is not an error
+            assert!(check_batch_clock(&clock, &batch, true, span).is_ok());
+            // The monitor has moved on to the last ULID of the batch
+            assert!(check_clock(&clock, 3_999, true, span).is_err());
+            assert!(check_batch_clock(&clock, &[], true, span).is_ok());
+        }
+
+        #[test]
+        fn test_custom_timestamp_is_not_checked() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            check_clock(&clock, FAR_FUTURE_MS, true, span).unwrap();
+
+            assert!(generate_single_ulid(Some(1704067200000), None, &clock, true, span).is_ok());
+            assert!(
+                generate_bulk_ulids(
+                    3,
+                    Some(BatchTimestamps::new(1704067200000, 0)),
+                    None,
+                    &clock,
+                    true,
+                    span
+                )
+                .is_ok()
+            );
+        }
+    }
+
+    mod append_ulids_tests {
+        use super::*;
+
+        fn temp_path(name: &str) -> PathBuf {
+            std::env::temp_dir().join(format!(
+                "nu_plugin_nw_ulid_{}_{}_{}.txt",
+                name,
+                std::process::id(),
+                UlidEngine::generate().unwrap()
+            ))
+        }
+
+        #[test]
+        fn test_append_accumulates_across_generations() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            let path = temp_path("append");
+
+            let mut expected = Vec::new();
+            for result in [
+                generate_bulk_ulids(3, None, None, &clock, false, span).unwrap(),
+                generate_single_ulid(None, None, &clock, false, span).unwrap(),
+            ] {
+                let PipelineData::Value(value, _) = result else {
+                    panic!("Expected pipeline value");
+                };
+                append_ulids(&path, &value, span).unwrap();
+                match value {
+                    Value::List { vals, .. } => {
+                        expected.extend(vals.iter().map(|v| v.as_str().unwrap().to_string()))
+                    }
+                    other => expected.push(other.as_str().unwrap().to_string()),
+                }
+            }
+
+            let contents = std::fs::read_to_string(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            let lines: Vec<&str> = contents.lines().collect();
+            assert_eq!(lines.len(), 4);
+            assert_eq!(lines, expected);
+        }
+
+        #[test]
+        fn test_append_to_missing_directory_errors() {
+            let path = temp_path("missing").join("ids.txt");
+            let value = Value::string("01AN4Z07BY79KA1307SR9X4MV3", create_test_span());
+            assert!(append_ulids(&path, &value, create_test_span()).is_err());
+        }
+    }
+
+    mod parse_batch_tests {
+        use super::*;
+
+        fn mixed_list() -> Vec<Value> {
+            let span = create_test_span();
+            vec![
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", span),
+                Value::string("invalid", span),
+                Value::int(42, span),
+                Value::string("01BX5ZZKBKACTAV9WEVGEMMVRY", span),
+            ]
+        }
+
+        #[test]
+        fn test_null_mode_keeps_positions() {
+            let result =
+                parse_batch(&mixed_list(), "null", None, None, false, create_test_span()).unwrap();
+            let vals = result.as_list().unwrap();
+            assert_eq!(vals.len(), 4);
+            assert!(vals[0].as_record().is_ok());
+            assert!(vals[1].is_nothing());
+            assert!(vals[2].is_nothing());
+            assert!(vals[3].as_record().is_ok());
+        }
+
+        #[test]
+        fn test_skip_mode_omits_invalid() {
+            let result =
+                parse_batch(&mixed_list(), "skip", None, None, false, create_test_span()).unwrap();
+            let vals = result.as_list().unwrap();
+            assert_eq!(vals.len(), 2);
+            assert!(vals.iter().all(|v| v.as_record().is_ok()));
+        }
+
+        #[test]
+        fn test_error_mode_aborts() {
+            assert!(
+                parse_batch(
+                    &mixed_list(),
+                    "error",
+                    None,
+                    None,
+                    false,
+                    create_test_span()
+                )
+                .is_err()
+            );
+        }
+
+        #[test]
+        fn test_error_mode_with_all_valid() {
+            let span = create_test_span();
+            let vals = vec![Value::string("01AN4Z07BY79KA1307SR9X4MV3", span)];
+            let result = parse_batch(&vals, "error", None, None, false, span).unwrap();
+            assert_eq!(result.as_list().unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_unknown_mode_errors() {
+            let list = Value::list(mixed_list(), create_test_span());
+            let error = on_invalid_mode(Some("ignore"), &list, create_test_span()).unwrap_err();
+            assert_eq!(error.msg, "Invalid mode");
+        }
+
+        #[test]
+        fn test_unknown_mode_errors_for_a_single_ulid() {
+            let single = Value::string("01AN4Z07BY79KA1307SR9X4MV3", create_test_span());
+            let error = on_invalid_mode(Some("ignore"), &single, create_test_span()).unwrap_err();
+            assert_eq!(error.msg, "Invalid mode");
+        }
+
+        #[test]
+        fn test_mode_is_rejected_for_a_single_ulid() {
+            let single = Value::string("invalid", create_test_span());
+            for mode in ["null", "skip", "error"] {
+                let error = on_invalid_mode(Some(mode), &single, create_test_span()).unwrap_err();
+                assert!(error.labels[0].text.contains("list"), "{}", mode);
+            }
+        }
+
+        #[test]
+        fn test_mode_defaults_to_error() {
+            let single = Value::string("invalid", create_test_span());
+            assert_eq!(
+                on_invalid_mode(None, &single, create_test_span()).unwrap(),
+                "error"
+            );
+            let list = Value::list(mixed_list(), create_test_span());
+            assert_eq!(
+                on_invalid_mode(Some("skip"), &list, create_test_span()).unwrap(),
+                "skip"
+            );
+        }
+    }
+
+    mod sortable_generation_tests {
+        use super::*;
+
+        fn ulid_strings(result: PipelineData) -> Vec<String> {
+            match result {
+                PipelineData::Value(Value::List { vals, .. }, _) => vals
+                    .iter()
+                    .map(|v| v.as_str().unwrap().to_string())
+                    .collect(),
+                _ => panic!("Expected list pipeline value"),
+            }
+        }
+
+        #[test]
+        fn test_sortable_batch_is_strictly_increasing() {
+            let span = create_test_span();
+            let mut generator = Generator::new();
+            let result = generate_bulk_ulids(
+                1_000,
+                None,
+                Some(&mut generator),
+                &ClockMonitor::default(),
+                false,
+                span,
+            )
+            .unwrap();
+
+            let ulids = ulid_strings(result);
+            assert_eq!(ulids.len(), 1_000);
+            assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+
+        #[test]
+        fn test_sortable_batch_with_timestamp_is_strictly_increasing() {
+            let span = create_test_span();
+            let mut generator = Generator::new();
+            let result = generate_bulk_ulids(
+                100,
+                Some(BatchTimestamps::new(1704067200000, 0)),
+                Some(&mut generator),
+                &ClockMonitor::default(),
+                false,
+                span,
+            )
+            .unwrap();
+
+            let ulids = ulid_strings(result);
+            assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+
+        #[test]
+        fn test_sortable_continues_across_calls() {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            let mut generator = Generator::new();
+
+            let first = ulid_strings(
+                generate_bulk_ulids(10, None, Some(&mut generator), &clock, false, span).unwrap(),
+            );
+            let second = ulid_strings(
+                generate_bulk_ulids(10, None, Some(&mut generator), &clock, false, span).unwrap(),
+            );
+            assert!(first.last().unwrap() < second.first().unwrap());
+        }
+    }
+
+    mod prefix_tests {
+        use super::*;
+
+        #[test]
+        fn test_parse_with_prefix() {
+            let components =
+                parse_with_prefix("user_01AN4Z07BY79KA1307SR9X4MV3", Some("user_")).unwrap();
+            assert_eq!(components.ulid, "01AN4Z07BY79KA1307SR9X4MV3");
+            assert_eq!(components.timestamp_ms, 1465824320894);
+        }
+
+        #[test]
+        fn test_parse_with_wrong_or_missing_prefix_errors() {
+            assert!(parse_with_prefix("user_01AN4Z07BY79KA1307SR9X4MV3", Some("order_")).is_err());
+            assert!(parse_with_prefix("user_01AN4Z07BY79KA1307SR9X4MV3", None).is_err());
+        }
+
+        #[test]
+        fn test_validate_with_prefix() {
+            assert!(validate_with_prefix(
+                "user_01AN4Z07BY79KA1307SR9X4MV3",
+                Some("user_")
+            ));
+            assert!(!validate_with_prefix(
+                "user_01AN4Z07BY79KA1307SR9X4MV3",
+                Some("order_")
+            ));
+            assert!(!validate_with_prefix("user_invalid", Some("user_")));
+            assert!(!validate_with_prefix(
+                "user_01AN4Z07BY79KA1307SR9X4MV3",
+                None
+            ));
+            assert!(validate_with_prefix("01AN4Z07BY79KA1307SR9X4MV3", None));
+        }
+
+        #[test]
+        fn test_parse_batch_with_prefix() {
+            let span = create_test_span();
+            let vals = vec![
+                Value::string("user_01AN4Z07BY79KA1307SR9X4MV3", span),
+                Value::string("01BX5ZZKBKACTAV9WEVGEMMVRY", span),
+            ];
+            let result = parse_batch(&vals, "null", Some("user_"), None, false, span).unwrap();
+            let vals = result.as_list().unwrap();
+            assert!(vals[0].as_record().is_ok());
+            assert!(vals[1].is_nothing());
+        }
+    }
+
+    mod implausible_timestamp_tests {
+        use super::*;
+
+        const YEAR_3000_MS: u64 = 32_503_680_000_000;
+
+        fn crafted_components(timestamp_ms: u64) -> UlidComponents {
+            let ulid = ulid::Ulid::from_parts(timestamp_ms, 0).to_string();
+            UlidEngine::parse(&ulid).unwrap()
+        }
+
+        #[test]
+        fn test_in_range_has_no_warning() {
+            let components = UlidEngine::parse("01AN4Z07BY79KA1307SR9X4MV3").unwrap();
+            let value =
+                build_parse_output(&components, Some((2015, 2100)), false, create_test_span())
+                    .unwrap();
+            assert!(
+                value
+                    .as_record()
+                    .unwrap()
+                    .get("timestamp_warning")
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_year_3000_has_warning() {
+            let components = crafted_components(YEAR_3000_MS);
+            let value =
+                build_parse_output(&components, Some((2015, 2100)), false, create_test_span())
+                    .unwrap();
+            let warning = value.as_record().unwrap().get("timestamp_warning").unwrap();
+            assert!(warning.as_str().unwrap().contains("3000"));
+        }
+
+        #[test]
+        fn test_no_warning_without_flag() {
+            let components = crafted_components(YEAR_3000_MS);
+            let value = build_parse_output(&components, None, false, create_test_span()).unwrap();
+            assert!(
+                value
+                    .as_record()
+                    .unwrap()
+                    .get("timestamp_warning")
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_batch_adds_warning_per_entry() {
+            let span = create_test_span();
+            let vals = vec![
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", span),
+                Value::string(ulid::Ulid::from_parts(YEAR_3000_MS, 0).to_string(), span),
+            ];
+            let result =
+                parse_batch(&vals, "error", None, Some((2015, 2100)), false, span).unwrap();
+            let vals = result.as_list().unwrap();
+            assert!(
+                vals[0]
+                    .as_record()
+                    .unwrap()
+                    .get("timestamp_warning")
+                    .is_none()
+            );
+            assert!(
+                vals[1]
+                    .as_record()
+                    .unwrap()
+                    .get("timestamp_warning")
+                    .is_some()
+            );
+        }
+    }
+
+    mod count_output_type_tests {
+        use super::*;
+
+        fn generate(count: Option<i64>) -> Value {
+            let span = create_test_span();
+            let clock = ClockMonitor::default();
+            let result = match count {
+                Some(count) => generate_bulk_ulids(count, None, None, &clock, false, span),
+                None => generate_single_ulid(None, None, &clock, false, span),
+            };
+            match result.unwrap() {
+                PipelineData::Value(value, _) => value,
+                _ => panic!("Expected pipeline value"),
+            }
+        }
+
+        #[test]
+        fn test_count_zero_is_empty_list() {
+            let value = generate(Some(0));
+            assert!(value.as_list().unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_count_one_is_single_item_list() {
+            let value = generate(Some(1));
+            let vals = value.as_list().unwrap();
+            assert_eq!(vals.len(), 1);
+            assert!(UlidEngine::validate(vals[0].as_str().unwrap()));
+        }
+
+        #[test]
+        fn test_no_count_is_bare_string() {
+            let value = generate(None);
+            assert!(matches!(value, Value::String { .. }));
+            assert!(UlidEngine::validate(value.as_str().unwrap()));
+        }
+    }
+
+    mod increment_tests {
+        use super::*;
+
+        const START: i64 = 1704067200000;
+
+        fn timestamps(result: PipelineData) -> Vec<u64> {
+            match result {
+                PipelineData::Value(Value::List { vals, .. }, _) => vals
+                    .iter()
+                    .map(|v| UlidEngine::extract_timestamp(v.as_str().unwrap()).unwrap())
+                    .collect(),
+                _ => panic!("Expected list pipeline value"),
+            }
+        }
+
+        #[test]
+        fn test_each_ulid_advances_by_increment() {
+            let span = create_test_span();
+            let result = generate_bulk_ulids(
+                5,
+                Some(BatchTimestamps::new(START, 1_000)),
+                None,
+                &ClockMonitor::default(),
+                false,
+                span,
+            )
+            .unwrap();
+
+            for (index, timestamp) in timestamps(result).into_iter().enumerate() {
+                assert_eq!(timestamp, START as u64 + index as u64 * 1_000);
+            }
+        }
+
+        #[test]
+        fn test_increment_with_sortable_generator() {
+            let span = create_test_span();
+            let mut generator = Generator::new();
+            let result = generate_bulk_ulids(
+                4,
+                Some(BatchTimestamps::new(START, 250)),
+                Some(&mut generator),
+                &ClockMonitor::default(),
+                false,
+                span,
+            )
+            .unwrap();
+
+            assert_eq!(
+                timestamps(result),
+                vec![
+                    START as u64,
+                    START as u64 + 250,
+                    START as u64 + 500,
+                    START as u64 + 750
+                ]
+            );
+        }
+
+        #[test]
+        fn test_zero_increment_keeps_start() {
+            let batch = BatchTimestamps::new(START, 0);
+            assert_eq!(batch.at(0).unwrap(), START as u64);
+            assert_eq!(batch.at(9).unwrap(), START as u64);
+        }
+
+        #[test]
+        fn test_increment_past_48_bit_range_errors() {
+            let batch = BatchTimestamps::new(crate::MAX_ULID_TIMESTAMP_MS as i64, 1);
+            assert!(batch.at(0).is_ok());
+            assert!(batch.at(1).is_err());
+            assert!(BatchTimestamps::new(START, i64::MAX).at(2).is_err());
+        }
+    }
+
+    mod uuid_confusion_tests {
+        use super::*;
+
+        const UUID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+        #[test]
+        fn test_uuid_error_suggests_conversion() {
+            let error = parse_failed(Some(UUID), "Invalid ULID".to_string(), create_test_span());
+            let help = error.help.unwrap();
+            assert!(help.contains("UUID"));
+            assert!(help.contains("--as-ulid"));
+        }
+
+        #[test]
+        fn test_other_errors_have_no_help() {
+            let error = parse_failed(
+                Some("not-a-ulid"),
+                "Invalid ULID".to_string(),
+                create_test_span(),
+            );
+            assert!(error.help.is_none());
+            assert!(
+                parse_failed(
+                    None,
+                    "Item 0 is not a string".to_string(),
+                    create_test_span()
+                )
+                .help
+                .is_none()
+            );
+        }
+
+        #[test]
+        fn test_batch_error_on_uuid_suggests_conversion() {
+            let span = create_test_span();
+            let vals = vec![
+                Value::string("01AN4Z07BY79KA1307SR9X4MV3", span),
+                Value::string(UUID, span),
+            ];
+            let error = parse_batch(&vals, "error", None, None, false, span).unwrap_err();
+            assert!(error.help.unwrap().contains("UUID"));
+        }
+
+        #[test]
+        fn test_validate_error_on_uuid_list_suggests_conversion() {
+            let span = create_test_span();
+            let vals = Value::list(vec![Value::string(UUID, span)], span);
+            let error = invalid_validate_input(&vals, span);
+            assert_eq!(error.msg, "Invalid input type");
+            assert!(error.help.unwrap().contains("--as-ulid"));
+
+            let other = Value::int(42, span);
+            assert!(invalid_validate_input(&other, span).help.is_none());
+        }
+
+        #[test]
+        fn test_validate_summary_error_on_single_uuid_suggests_conversion() {
+            let span = create_test_span();
+            let error = summarise_validation(Value::string(UUID, span), span).unwrap_err();
+            assert_eq!(error.msg, "Invalid input");
+            assert!(error.help.unwrap().contains("UUID"));
+
+            let error = summarise_validation(Value::string("invalid", span), span).unwrap_err();
+            assert!(error.help.is_none());
+        }
+    }
+
+    mod include_bytes_tests {
+        use super::*;
+
+        const ULID: &str = "01AN4Z07BY79KA1307SR9X4MV3";
+
+        fn parse_output(include_bytes: bool) -> Value {
+            let components = UlidEngine::parse(ULID).unwrap();
+            build_parse_output(&components, None, include_bytes, create_test_span()).unwrap()
+        }
+
+        #[test]
+        fn test_signature_has_include_bytes() {
+            let signature = UlidParseCommand.signature();
+            assert!(
+                signature
+                    .named
+                    .iter()
+                    .any(|flag| flag.long == "include-bytes")
+            );
+        }
+
+        #[test]
+        fn test_bytes_absent_without_flag() {
+            assert!(
+                parse_output(false)
+                    .as_record()
+                    .unwrap()
+                    .get("bytes")
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_bytes_round_trip_to_ulid() {
+            let value = parse_output(true);
+            let bytes = value
+                .as_record()
+                .unwrap()
+                .get("bytes")
+                .unwrap()
+                .as_binary()
+                .unwrap();
+            assert_eq!(bytes.len(), 16);
+
+            let bytes: [u8; 16] = bytes.try_into().unwrap();
+            assert_eq!(ulid::Ulid::from_bytes(bytes).to_string(), ULID);
+        }
+
+        #[test]
+        fn test_batch_adds_bytes_per_entry() {
+            let span = create_test_span();
+            let vals = vec![
+                Value::string(ULID, span),
+                Value::string("01BX5ZZKBKACTAV9WEVGEMMVRY", span),
+            ];
+            let result = parse_batch(&vals, "error", None, None, true, span).unwrap();
+            for value in result.as_list().unwrap() {
+                assert!(value.as_record().unwrap().get("bytes").is_some());
+            }
+        }
+    }
 }
@@ -0,0 +1,286 @@
+//! ULID ordering verification command.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::{UlidEngine, UlidPlugin};
+
+/// Checks that a list of ULIDs is already in order.
+///
+/// Scans once and stops at the first out-of-order pair, so it is cheaper than sorting the
+/// list and comparing. Equal timestamps count as ordered unless `--natural` is given.
+pub struct UlidVerifyOrderCommand;
+
+impl PluginCommand for UlidVerifyOrderCommand {
+    type Plugin = UlidPlugin;
+
+    fn name(&self) -> &str {
+        "ulid verify-order"
+    }
+
+    fn description(&self) -> &str {
+        "Check that a list of ULIDs is in timestamp order, reporting the first violation"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .switch(
+                "natural",
+                "Compare full ULID strings instead of timestamps",
+                Some('n'),
+            )
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::String)),
+                Type::Record(vec![].into()),
+            )])
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "open events.csv | get id | ulid verify-order",
+                description: "Check that an ID column is in timestamp order",
+                result: None,
+            },
+            Example {
+                example: "$ids | ulid verify-order --natural | get ordered",
+                description: "Check that ULIDs are in strict string order",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let natural = call.has_flag("natural")?;
+
+        let record = match input {
+            PipelineData::Value(Value::List { vals, .. }, _) => {
+                verify_order(vals.into_iter(), natural, call.head)?
+            }
+            PipelineData::ListStream(stream, _) => {
+                verify_order(stream.into_iter(), natural, call.head)?
+            }
+            _ => {
+                return Err(LabeledError::new("Invalid input")
+                    .with_label("Expected a list of ULIDs", call.head));
+            }
+        };
+
+        Ok(PipelineData::Value(record, None))
+    }
+}
+
+/// Key that must not decrease from one ULID to the next.
+#[derive(PartialEq, PartialOrd)]
+enum OrderKey {
+    Timestamp(u64),
+    Natural(u128),
+}
+
+fn order_key(
+    value: &Value,
+    index: usize,
+    natural: bool,
+    span: Span,
+) -> Result<OrderKey, LabeledError> {
+    let ulid_str = value.as_str().map_err(|_| {
+        LabeledError::new("Invalid input")
+            .with_label(format!("Item {} is not a string", index), span)
+    })?;
+
+    if natural {
+        // Compare the decoded value so lowercase and uppercase spellings order alike
+        return ulid::Ulid::from_string(ulid_str)
+            .map(|ulid| OrderKey::Natural(ulid.0))
+            .map_err(|_| {
+                LabeledError::new("Invalid ULID").with_label(
+                    format!("Item {}: '{}' is not a valid ULID", index, ulid_str),
+                    span,
+                )
+            });
+    }
+
+    UlidEngine::extract_timestamp(ulid_str)
+        .map(OrderKey::Timestamp)
+        .map_err(|e| {
+            LabeledError::new("Invalid ULID").with_label(format!("Item {}: {}", index, e), span)
+        })
+}
+
+fn verify_order(
+    values: impl Iterator<Item = Value>,
+    natural: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let mut previous: Option<(OrderKey, Value)> = None;
+
+    for (index, value) in values.enumerate() {
+        let key = order_key(&value, index, natural, span)?;
+
+        let out_of_order = previous
+            .take()
+            .filter(|(previous_key, _)| key < *previous_key);
+        if let Some((_, previous_value)) = out_of_order {
+            let mut violation = Record::new();
+            violation.push("previous", previous_value);
+            violation.push("current", value);
+            return Ok(order_record(
+                Some(index),
+                Value::record(violation, span),
+                span,
+            ));
+        }
+
+        previous = Some((key, value));
+    }
+
+    Ok(order_record(None, Value::nothing(span), span))
+}
+
+fn order_record(first_violation_index: Option<usize>, violation: Value, span: Span) -> Value {
+    let mut record = Record::new();
+    record.push(
+        "ordered",
+        Value::bool(first_violation_index.is_none(), span),
+    );
+    record.push(
+        "first_violation_index",
+        match first_violation_index {
+            Some(index) => Value::int(index as i64, span),
+            None => Value::nothing(span),
+        },
+    );
+    record.push("violation", violation);
+    Value::record(record, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EARLY: &str = "01AN4Z07BY79KA1307SR9X4MV3";
+    const LATE: &str = "01BX5ZZKBKACTAV9WEVGEMMVRY";
+
+    fn test_span() -> Span {
+        Span::test_data()
+    }
+
+    fn verify(ids: &[&str], natural: bool) -> Record {
+        let vals = ids.iter().map(|id| Value::string(*id, test_span()));
+        verify_order(vals, natural, test_span())
+            .unwrap()
+            .as_record()
+            .unwrap()
+            .clone()
+    }
+
+    mod verify_order_command {
+        use super::*;
+
+        #[test]
+        fn test_command_signature() {
+            let sig = UlidVerifyOrderCommand.signature();
+            assert_eq!(sig.name, "ulid verify-order");
+            assert!(sig.named.iter().any(|f| f.long == "natural"));
+        }
+
+        #[test]
+        fn test_command_examples_not_empty() {
+            assert!(!UlidVerifyOrderCommand.examples().is_empty());
+        }
+    }
+
+    mod verify_order_tests {
+        use super::*;
+
+        #[test]
+        fn test_ordered_list() {
+            let record = verify(&[EARLY, EARLY, LATE], false);
+            assert!(record.get("ordered").unwrap().as_bool().unwrap());
+            assert!(record.get("first_violation_index").unwrap().is_nothing());
+            assert!(record.get("violation").unwrap().is_nothing());
+        }
+
+        #[test]
+        fn test_reports_first_violation() {
+            let record = verify(&[EARLY, LATE, EARLY, LATE, EARLY], false);
+            assert!(!record.get("ordered").unwrap().as_bool().unwrap());
+            assert_eq!(
+                record
+                    .get("first_violation_index")
+                    .unwrap()
+                    .as_int()
+                    .unwrap(),
+                2
+            );
+
+            let violation = record.get("violation").unwrap().as_record().unwrap();
+            assert_eq!(violation.get("previous").unwrap().as_str().unwrap(), LATE);
+            assert_eq!(violation.get("current").unwrap().as_str().unwrap(), EARLY);
+        }
+
+        #[test]
+        fn test_equal_timestamps_are_ordered_unless_natural() {
+            let same_ms_high = "01AN4Z07BYZZZZZZZZZZZZZZZZ";
+            let same_ms_low = "01AN4Z07BY0000000000000000";
+            let ids = [same_ms_high, same_ms_low];
+
+            assert!(
+                verify(&ids, false)
+                    .get("ordered")
+                    .unwrap()
+                    .as_bool()
+                    .unwrap()
+            );
+            assert!(
+                !verify(&ids, true)
+                    .get("ordered")
+                    .unwrap()
+                    .as_bool()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_natural_order_ignores_case() {
+            let early_lower = EARLY.to_lowercase();
+            let ids = [early_lower.as_str(), LATE];
+
+            assert!(
+                verify(&ids, true)
+                    .get("ordered")
+                    .unwrap()
+                    .as_bool()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_empty_list_is_ordered() {
+            assert!(
+                verify(&[], false)
+                    .get("ordered")
+                    .unwrap()
+                    .as_bool()
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_invalid_ulid_errors() {
+            let vals = [EARLY, "invalid"]
+                .iter()
+                .map(|id| Value::string(*id, test_span()));
+            assert!(verify_order(vals, false, test_span()).is_err());
+        }
+    }
+}
@@ -1,5 +1,7 @@
 //! Nushell plugin providing ULID generation, validation, and analysis utilities.
 
+use std::sync::Mutex;
+
 use nu_plugin::{Plugin, PluginCommand};
 
 mod commands;
@@ -11,7 +13,13 @@ pub use security::*;
 pub use ulid_engine::*;
 
 /// Top-level plugin type registered with the Nushell plugin host.
-pub struct UlidPlugin;
+///
+/// State held here lives for as long as Nushell keeps the plugin process running.
+#[derive(Default)]
+pub struct UlidPlugin {
+    clock: ClockMonitor,
+    generator: Mutex<ulid::Generator>,
+}
 
 impl Plugin for UlidPlugin {
     fn version(&self) -> String {
@@ -22,10 +30,17 @@ impl Plugin for UlidPlugin {
         vec![
             // Core ULID commands
             Box::new(UlidGenerateCommand),
+            Box::new(UlidFromTimestampCommand),
             Box::new(UlidValidateCommand),
+            Box::new(UlidValidateSummaryCommand),
+            Box::new(UlidIsValidCommand),
             Box::new(UlidParseCommand),
             Box::new(UlidInspectCommand),
+            Box::new(UlidExplainCommand),
             Box::new(UlidSortCommand),
+            Box::new(UlidDownsampleCommand),
+            Box::new(UlidVerifyOrderCommand),
+            Box::new(UlidStatsCommand),
             Box::new(UlidSecurityAdviceCommand),
             // Plugin info
             Box::new(UlidInfoCommand),
@@ -34,12 +49,17 @@ impl Plugin for UlidPlugin {
             Box::new(UlidTimeParseCommand),
             Box::new(UlidTimeMillisCommand),
             // Encoding utilities
+            Box::new(UlidEncodeCommand),
+            Box::new(UlidDecodeCommand),
             Box::new(UlidEncodeBase32Command),
             Box::new(UlidDecodeBase32Command),
             Box::new(UlidEncodeHexCommand),
             Box::new(UlidDecodeHexCommand),
+            Box::new(UlidEncodeBase64Command),
+            Box::new(UlidDecodeBase64Command),
             // Binary conversion
             Box::new(UlidToBytesCommand),
+            Box::new(UlidConvertCommand),
         ]
     }
 }
@@ -50,26 +70,38 @@ mod tests {
 
     #[test]
     fn test_plugin_version() {
-        let plugin = UlidPlugin;
+        let plugin = UlidPlugin::default();
         assert!(!plugin.version().is_empty());
     }
 
     #[test]
     fn test_plugin_commands() {
-        let plugin = UlidPlugin;
+        let plugin = UlidPlugin::default();
         let commands = plugin.commands();
-        assert_eq!(commands.len(), 15);
+        assert_eq!(commands.len(), 27);
 
         // Test key commands to ensure they're registered correctly
         let command_names: Vec<&str> = commands.iter().map(|cmd| cmd.name()).collect();
         assert!(command_names.contains(&"ulid generate"));
         assert!(command_names.contains(&"ulid validate"));
+        assert!(command_names.contains(&"ulid validate summary"));
+        assert!(command_names.contains(&"ulid is-valid"));
+        assert!(command_names.contains(&"ulid convert"));
         assert!(command_names.contains(&"ulid parse"));
         assert!(command_names.contains(&"ulid inspect"));
+        assert!(command_names.contains(&"ulid explain"));
+        assert!(command_names.contains(&"ulid from-timestamp"));
+        assert!(command_names.contains(&"ulid downsample"));
+        assert!(command_names.contains(&"ulid verify-order"));
+        assert!(command_names.contains(&"ulid encode base64"));
+        assert!(command_names.contains(&"ulid decode base64"));
         assert!(command_names.contains(&"ulid sort"));
+        assert!(command_names.contains(&"ulid stats"));
         assert!(command_names.contains(&"ulid security-advice"));
         assert!(command_names.contains(&"ulid info"));
         assert!(command_names.contains(&"ulid time now"));
+        assert!(command_names.contains(&"ulid encode"));
+        assert!(command_names.contains(&"ulid decode"));
         assert!(command_names.contains(&"ulid encode base32"));
     }
 }
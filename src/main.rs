@@ -4,5 +4,5 @@ use nu_plugin::{MsgPackSerializer, serve_plugin};
 use nu_plugin_nw_ulid::UlidPlugin;
 
 fn main() {
-    serve_plugin(&UlidPlugin, MsgPackSerializer {})
+    serve_plugin(&UlidPlugin::default(), MsgPackSerializer {})
 }
@@ -1,14 +1,32 @@
 //! Core ULID engine providing all ULID operations for the plugin.
 
 use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
 
+use chrono::Datelike;
 use nu_protocol::{Record, Span, Value};
 use serde::{Deserialize, Serialize};
-use ulid::Ulid;
+use ulid::{Generator, Ulid};
 
 /// Length of a ULID string in Crockford Base32 encoding.
 pub const ULID_STRING_LENGTH: usize = 26;
 
+/// Length of a ULID in its native binary representation.
+pub const ULID_BYTE_LENGTH: usize = 16;
+
+/// Length of a UUID in its hyphenated 8-4-4-4-12 text form.
+pub const UUID_HYPHENATED_LENGTH: usize = 36;
+
+/// Earliest year considered a plausible ULID timestamp by default; the spec dates from 2016.
+pub const DEFAULT_MIN_PLAUSIBLE_YEAR: i32 = 2015;
+
+/// Latest year considered a plausible ULID timestamp by default.
+pub const DEFAULT_MAX_PLAUSIBLE_YEAR: i32 = 2100;
+
+/// Largest timestamp that fits in the 48-bit ULID timestamp field.
+pub const MAX_ULID_TIMESTAMP_MS: u64 = (1 << 48) - 1;
+
 /// Maximum number of ULIDs in a single bulk generation request.
 pub const MAX_BULK_GENERATION: usize = 10_000;
 
@@ -59,6 +77,9 @@ impl UlidEngine {
     }
 
     /// Generates multiple ULIDs efficiently.
+    ///
+    /// Each ULID draws 80 fresh random bits, so uniqueness within the batch is probabilistic
+    /// rather than checked. Use [`Self::generate_monotonic`] when uniqueness must be guaranteed.
     pub fn generate_bulk(count: usize) -> Result<Vec<Ulid>, UlidError> {
         if count == 0 {
             return Ok(Vec::new());
@@ -78,6 +99,84 @@ impl UlidEngine {
         Ok(result)
     }
 
+    /// Shifts a ULID timestamp by a signed millisecond offset.
+    ///
+    /// Fails if the result falls before the Unix epoch or beyond the 48-bit range.
+    pub fn offset_timestamp(timestamp_ms: u64, offset_ms: i64) -> Result<u64, UlidError> {
+        let shifted = i128::from(timestamp_ms) + i128::from(offset_ms);
+
+        if shifted < 0 {
+            return Err(UlidError::InvalidInput {
+                message: format!(
+                    "Offset of {} ms moves timestamp {} before the Unix epoch",
+                    offset_ms, timestamp_ms
+                ),
+            });
+        }
+
+        if shifted > i128::from(MAX_ULID_TIMESTAMP_MS) {
+            return Err(UlidError::TimestampOutOfRange {
+                timestamp: shifted as u64,
+                max_timestamp: MAX_ULID_TIMESTAMP_MS,
+            });
+        }
+
+        Ok(shifted as u64)
+    }
+
+    /// Returns the smallest and largest ULIDs with the given timestamp.
+    ///
+    /// Every ULID generated in that millisecond falls within these inclusive bounds, which
+    /// makes them usable as range-query keys. Fails if the timestamp exceeds the 48-bit range.
+    pub fn bounds_at(timestamp_ms: u64) -> Result<(Ulid, Ulid), UlidError> {
+        if timestamp_ms > MAX_ULID_TIMESTAMP_MS {
+            return Err(UlidError::TimestampOutOfRange {
+                timestamp: timestamp_ms,
+                max_timestamp: MAX_ULID_TIMESTAMP_MS,
+            });
+        }
+
+        Ok((
+            Ulid::from_parts(timestamp_ms, 0),
+            Ulid::from_parts(timestamp_ms, ULID_RANDOMNESS_MASK),
+        ))
+    }
+
+    /// Returns a warning if the timestamp's UTC year falls outside `min_year..=max_year`.
+    ///
+    /// Such timestamps often indicate a malformed or hand-crafted ULID.
+    #[must_use]
+    pub fn timestamp_warning(timestamp_ms: u64, min_year: i32, max_year: i32) -> Option<String> {
+        let year = chrono::DateTime::from_timestamp_millis(timestamp_ms as i64)?.year();
+        if (min_year..=max_year).contains(&year) {
+            None
+        } else {
+            Some(format!(
+                "Timestamp year {} is outside the plausible range {}-{}",
+                year, min_year, max_year
+            ))
+        }
+    }
+
+    /// Generates a ULID from a monotonic generator.
+    ///
+    /// ULIDs from the same generator within one millisecond increment the randomness
+    /// instead of drawing it afresh, so they sort in generation order.
+    pub fn generate_monotonic(
+        generator: &mut Generator,
+        timestamp_ms: Option<u64>,
+    ) -> Result<Ulid, UlidError> {
+        let result = match timestamp_ms {
+            Some(ts) => {
+                generator.generate_from_datetime(SystemTime::UNIX_EPOCH + Duration::from_millis(ts))
+            }
+            None => generator.generate(),
+        };
+        result.map_err(|e| UlidError::GenerationError {
+            reason: e.to_string(),
+        })
+    }
+
     /// Parses a ULID string into components.
     pub fn parse(ulid_str: &str) -> Result<UlidComponents, UlidError> {
         match Ulid::from_str(ulid_str) {
@@ -97,10 +196,47 @@ impl UlidEngine {
         }
     }
 
+    /// Removes a known type prefix, returning the bare ULID string.
+    pub fn strip_prefix<'a>(id: &'a str, prefix: &str) -> Result<&'a str, UlidError> {
+        id.strip_prefix(prefix)
+            .ok_or_else(|| UlidError::InvalidFormat {
+                input: id.to_string(),
+                reason: format!("Expected prefix '{}'", prefix),
+            })
+    }
+
     /// Returns `true` if the string is a valid ULID.
+    ///
+    /// Wrong-length input is rejected before decoding, which keeps bulk validation of
+    /// mostly-invalid data cheap.
     #[must_use]
     pub fn validate(ulid_str: &str) -> bool {
-        Ulid::from_str(ulid_str).is_ok()
+        ulid_str.len() == ULID_STRING_LENGTH && Ulid::from_str(ulid_str).is_ok()
+    }
+
+    /// Returns `true` if the string has the hyphenated 8-4-4-4-12 hex shape of a UUID.
+    ///
+    /// Used to give a more helpful error when a UUID is passed where a ULID is expected.
+    #[must_use]
+    pub fn looks_like_uuid(input: &str) -> bool {
+        const HYPHEN_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+
+        input.len() == UUID_HYPHENATED_LENGTH
+            && input.char_indices().all(|(index, c)| {
+                if HYPHEN_POSITIONS.contains(&index) {
+                    c == '-'
+                } else {
+                    c.is_ascii_hexdigit()
+                }
+            })
+    }
+
+    /// Returns `true` if the bytes are a valid binary ULID.
+    ///
+    /// Every 16-byte value decodes to a ULID, so only the length is checked.
+    #[must_use]
+    pub fn validate_bytes(bytes: &[u8]) -> bool {
+        bytes.len() == ULID_BYTE_LENGTH
     }
 
     /// Extracts the timestamp from a ULID.
@@ -169,6 +305,27 @@ impl UlidEngine {
     }
 }
 
+/// Tracks the latest generation timestamp to detect the wall clock moving backward.
+///
+/// ULIDs take their timestamp from the system wall clock, which can step backward on NTP
+/// adjustments. IDs generated after such a step sort before IDs generated earlier.
+#[derive(Debug, Default)]
+pub struct ClockMonitor {
+    last_timestamp_ms: AtomicU64,
+}
+
+impl ClockMonitor {
+    /// Records a generation timestamp.
+    ///
+    /// Returns the latest previously observed timestamp if `timestamp_ms` is earlier than it.
+    pub fn observe(&self, timestamp_ms: u64) -> Option<u64> {
+        let previous = self
+            .last_timestamp_ms
+            .fetch_max(timestamp_ms, Ordering::Relaxed);
+        (timestamp_ms < previous).then_some(previous)
+    }
+}
+
 /// Errors produced by ULID operations.
 #[derive(Debug, Clone)]
 pub enum UlidError {
@@ -247,6 +404,158 @@ mod tests {
         assert!(!UlidEngine::validate("01AN4Z07BY79KA1307SR9X4MV34")); // Too long
     }
 
+    #[test]
+    fn test_validation_length_fast_path() {
+        // Wrong lengths, including empty and whitespace-only input
+        for input in ["", " ", "   \t\n", "01AN4Z07BY79KA1307SR9X4MV3 "] {
+            assert!(!UlidEngine::validate(input));
+            assert_eq!(UlidEngine::validate(input), Ulid::from_str(input).is_ok());
+        }
+
+        // Right length still goes through the full decode
+        assert!(UlidEngine::validate("01an4z07by79ka1307sr9x4mv3"));
+        assert!(!UlidEngine::validate("01AN4Z07BY79KA1307SR9X4M!@"));
+        assert!(!UlidEngine::validate(&" ".repeat(ULID_STRING_LENGTH)));
+    }
+
+    #[test]
+    fn test_monotonic_generation_is_strictly_increasing() {
+        let mut generator = Generator::new();
+        let ulids: Vec<Ulid> = (0..1_000)
+            .map(|_| UlidEngine::generate_monotonic(&mut generator, None).unwrap())
+            .collect();
+        assert!(ulids.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let fixed: Vec<Ulid> = (0..100)
+            .map(|_| {
+                UlidEngine::generate_monotonic(&mut generator, Some(1_704_067_200_000)).unwrap()
+            })
+            .collect();
+        assert!(fixed.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(
+            fixed
+                .iter()
+                .all(|ulid| ulid.timestamp_ms() == 1_704_067_200_000)
+        );
+    }
+
+    #[test]
+    fn test_clock_monitor_detects_backward_movement() {
+        let monitor = ClockMonitor::default();
+        assert_eq!(monitor.observe(1_000), None);
+        assert_eq!(monitor.observe(1_000), None);
+        assert_eq!(monitor.observe(2_000), None);
+        assert_eq!(monitor.observe(1_500), Some(2_000));
+        // The high-water mark is kept after a regression
+        assert_eq!(monitor.observe(1_800), Some(2_000));
+        assert_eq!(monitor.observe(2_001), None);
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let prefixed = "user_01AN4Z07BY79KA1307SR9X4MV3";
+        assert_eq!(
+            UlidEngine::strip_prefix(prefixed, "user_").unwrap(),
+            "01AN4Z07BY79KA1307SR9X4MV3"
+        );
+        assert!(UlidEngine::strip_prefix(prefixed, "order_").is_err());
+        assert!(UlidEngine::strip_prefix("01AN4Z07BY79KA1307SR9X4MV3", "user_").is_err());
+    }
+
+    #[test]
+    fn test_offset_timestamp() {
+        assert_eq!(UlidEngine::offset_timestamp(1_000, 500).unwrap(), 1_500);
+        assert_eq!(UlidEngine::offset_timestamp(1_000, -1_000).unwrap(), 0);
+        assert_eq!(
+            UlidEngine::offset_timestamp(MAX_ULID_TIMESTAMP_MS - 1, 1).unwrap(),
+            MAX_ULID_TIMESTAMP_MS
+        );
+
+        assert!(matches!(
+            UlidEngine::offset_timestamp(1_000, -1_001),
+            Err(UlidError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            UlidEngine::offset_timestamp(MAX_ULID_TIMESTAMP_MS, 1),
+            Err(UlidError::TimestampOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_generate_with_timestamp_round_trips_timestamp() {
+        // Edges, every single-bit timestamp, and an even spread across the 48-bit range
+        let edges = [0, 1, MAX_ULID_TIMESTAMP_MS - 1, MAX_ULID_TIMESTAMP_MS];
+        let single_bits = (0..48).map(|bit| 1u64 << bit);
+        let spread = (0..1_000u64).map(|i| i * (MAX_ULID_TIMESTAMP_MS / 1_000) + i);
+
+        for timestamp_ms in edges.into_iter().chain(single_bits).chain(spread) {
+            let ulid = UlidEngine::generate_with_timestamp(timestamp_ms).unwrap();
+            let components = UlidEngine::parse(&ulid.to_string()).unwrap();
+
+            assert_eq!(components.timestamp_ms, timestamp_ms);
+            assert_eq!(ulid.timestamp_ms(), timestamp_ms);
+            assert_eq!(ulid.random() & !ULID_RANDOMNESS_MASK, 0);
+        }
+    }
+
+    #[test]
+    fn test_bounds_at() {
+        let (min, max) = UlidEngine::bounds_at(1465824320894).unwrap();
+        assert_eq!(min.to_string(), "01AN4Z07BY0000000000000000");
+        assert_eq!(max.to_string(), "01AN4Z07BYZZZZZZZZZZZZZZZZ");
+        assert_eq!(min.timestamp_ms(), max.timestamp_ms());
+        assert!(min < max);
+
+        assert!(UlidEngine::bounds_at(MAX_ULID_TIMESTAMP_MS).is_ok());
+        assert!(matches!(
+            UlidEngine::bounds_at(MAX_ULID_TIMESTAMP_MS + 1),
+            Err(UlidError::TimestampOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_warning() {
+        // 2016-06-13
+        assert!(UlidEngine::timestamp_warning(1465824320894, 2015, 2100).is_none());
+        // 3000-01-01
+        let warning = UlidEngine::timestamp_warning(32503680000000, 2015, 2100).unwrap();
+        assert!(warning.contains("3000"));
+        // 1970-01-01
+        assert!(UlidEngine::timestamp_warning(0, 2015, 2100).is_some());
+        // Window bounds are inclusive
+        assert!(UlidEngine::timestamp_warning(1465824320894, 2016, 2016).is_none());
+    }
+
+    #[test]
+    fn test_looks_like_uuid() {
+        assert!(UlidEngine::looks_like_uuid(
+            "550e8400-e29b-41d4-a716-446655440000"
+        ));
+        assert!(UlidEngine::looks_like_uuid(
+            "550E8400-E29B-41D4-A716-446655440000"
+        ));
+
+        assert!(!UlidEngine::looks_like_uuid("01AN4Z07BY79KA1307SR9X4MV3"));
+        assert!(!UlidEngine::looks_like_uuid(
+            "550e8400e29b41d4a716446655440000"
+        ));
+        assert!(!UlidEngine::looks_like_uuid(
+            "550e8400-e29b-41d4-a716-44665544000g"
+        ));
+        assert!(!UlidEngine::looks_like_uuid(
+            "550e8400-e29b41d4--a716-446655440000"
+        ));
+    }
+
+    #[test]
+    fn test_binary_validation() {
+        assert!(UlidEngine::validate_bytes(&[0u8; 16]));
+        assert!(UlidEngine::validate_bytes(&[0xFFu8; 16]));
+        assert!(!UlidEngine::validate_bytes(&[0u8; 15]));
+        assert!(!UlidEngine::validate_bytes(&[0u8; 17]));
+        assert!(!UlidEngine::validate_bytes(&[]));
+    }
+
     #[test]
     fn test_ulid_parsing() {
         let ulid_str = "01AN4Z07BY79KA1307SR9X4MV3";